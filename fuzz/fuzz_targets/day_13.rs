@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc_2022::days::day_13::Day13;
+use aoc_2022::solution::Runnable;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day13.parse_erased(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc_2022::days::day_19::Day19;
+use aoc_2022::solution::Runnable;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day19::default().parse_erased(input);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc_2022::days::day_21::Day21;
+use aoc_2022::solution::Runnable;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day21.parse_erased(input);
+});
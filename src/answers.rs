@@ -0,0 +1,506 @@
+use crate::solution::{Answer, Part};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// The known correct answers for each day, as stored in `answers.toml`:
+///
+/// ```toml
+/// [day_1]
+/// part_one = 69626
+/// part_two = 206780
+///
+/// [day_5]
+/// part_one = "VGBBJCRMN"
+/// ```
+///
+/// Either part may be left out if it hasn't been solved yet. Multi-line answers (day 10's CRT)
+/// are written as `'''` strings. Answers the website rejected are kept next to the correct ones
+/// so that `aoc submit` doesn't send them again:
+///
+/// ```toml
+/// [day_16]
+/// rejected_part_one = [{ answer = 2000, reason = "too_high" }]
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerStore {
+    days: BTreeMap<String, DayAnswers>,
+}
+
+/// The known answers for a single day. This is also the format of the expected answers that sit
+/// alongside each example in `inputs/tests/day_N/`.
+#[derive(Debug, Default, Deserialize)]
+pub struct DayAnswers {
+    part_one: Option<toml::Value>,
+    part_two: Option<toml::Value>,
+    #[serde(default)]
+    rejected_part_one: Vec<Rejected>,
+    #[serde(default)]
+    rejected_part_two: Vec<Rejected>,
+}
+
+impl DayAnswers {
+    pub fn expected(&self, part: Part) -> Option<String> {
+        let value = match part {
+            Part::One => self.part_one.as_ref(),
+            Part::Two => self.part_two.as_ref(),
+        }?;
+        Some(answer_text(value))
+    }
+
+    pub fn rejected(&self, part: Part) -> &[Rejected] {
+        match part {
+            Part::One => &self.rejected_part_one,
+            Part::Two => &self.rejected_part_two,
+        }
+    }
+}
+
+fn answer_text(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(text) => text.trim_end().to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// An answer the website said was wrong.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Rejected {
+    answer: toml::Value,
+    pub reason: Rejection,
+}
+
+impl Rejected {
+    pub fn answer(&self) -> String {
+        answer_text(&self.answer)
+    }
+}
+
+/// What the website said about a wrong answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Rejection {
+    TooHigh,
+    TooLow,
+    /// Wrong without a hint, as for text answers.
+    Wrong,
+}
+
+impl Rejection {
+    fn name(&self) -> &'static str {
+        match self {
+            Rejection::TooHigh => "too_high",
+            Rejection::TooLow => "too_low",
+            Rejection::Wrong => "wrong",
+        }
+    }
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejection::TooHigh => write!(f, "too high"),
+            Rejection::TooLow => write!(f, "too low"),
+            Rejection::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+/// Why an answer isn't worth submitting, going by what is already in the store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect,
+    DiffersFromCorrect {
+        expected: String,
+    },
+    AlreadyRejected(Rejection),
+    /// A number at or above an answer that was too high, or at or below one that was too low.
+    BeyondBound {
+        bound: String,
+        reason: Rejection,
+    },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect => write!(f, "it is already known to be correct"),
+            Refusal::DiffersFromCorrect { expected } => {
+                write!(f, "the correct answer is already known to be {}", expected)
+            }
+            Refusal::AlreadyRejected(reason) => {
+                write!(f, "it was already submitted and was {}", reason)
+            }
+            Refusal::BeyondBound { bound, reason } => {
+                write!(f, "{} was already {}", bound, reason)
+            }
+        }
+    }
+}
+
+/// How a computed answer compares with the one in the store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckResult {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl AnswerStore {
+    pub fn load(path: &Path) -> Result<Self, AnswerStoreError> {
+        let text = std::fs::read_to_string(path).map_err(|source| AnswerStoreError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        Self::parse(&text).map_err(|source| AnswerStoreError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn parse(text: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(text)
+    }
+
+    /// The known answer for the given day and part, if there is one.
+    pub fn expected(&self, day: u8, part: Part) -> Option<String> {
+        self.days.get(&format!("day_{}", day))?.expected(part)
+    }
+
+    /// Whether `answer` can already be judged without asking the website, either because the
+    /// correct answer is known or because it was (or is beyond) a rejected answer.
+    pub fn refusal(&self, day: u8, part: Part, answer: &str) -> Option<Refusal> {
+        let day_answers = self.days.get(&format!("day_{}", day))?;
+        if let Some(expected) = day_answers.expected(part) {
+            return Some(if expected == answer {
+                Refusal::AlreadyCorrect
+            } else {
+                Refusal::DiffersFromCorrect { expected }
+            });
+        }
+
+        let rejected = day_answers.rejected(part);
+        if let Some(previous) = rejected.iter().find(|rejected| rejected.answer() == answer) {
+            return Some(Refusal::AlreadyRejected(previous.reason));
+        }
+        let number = answer.parse::<i64>().ok()?;
+        rejected.iter().find_map(|rejected| {
+            let bound = rejected.answer.as_integer()?;
+            let beyond = match rejected.reason {
+                Rejection::TooHigh => number >= bound,
+                Rejection::TooLow => number <= bound,
+                Rejection::Wrong => false,
+            };
+            beyond.then(|| Refusal::BeyondBound {
+                bound: bound.to_string(),
+                reason: rejected.reason,
+            })
+        })
+    }
+
+    pub fn check(&self, day: u8, part: Part, actual: &Answer) -> CheckResult {
+        match self.expected(day, part) {
+            None => CheckResult::Missing,
+            Some(expected) if expected == actual.to_string() => CheckResult::Pass,
+            Some(expected) => CheckResult::Fail { expected },
+        }
+    }
+}
+
+/// Saves `answer` as the correct answer for the day and part in the answers file at `path`,
+/// creating the file if needed. Everything else in the file, including comments, is kept as is.
+pub fn record_correct(
+    path: &Path,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<(), AnswerStoreError> {
+    edit_day(path, day, |table| {
+        table[part_key("", part).as_str()] = toml_edit::value(answer_value(answer));
+    })
+}
+
+/// Adds `answer` to the rejected answers for the day and part in the answers file at `path`.
+pub fn record_rejected(
+    path: &Path,
+    day: u8,
+    part: Part,
+    answer: &str,
+    reason: Rejection,
+) -> Result<(), AnswerStoreError> {
+    edit_day(path, day, |table| {
+        let mut rejected = toml_edit::InlineTable::new();
+        rejected.insert("answer", answer_value(answer));
+        rejected.insert("reason", reason.name().into());
+
+        let key = part_key("rejected_", part);
+        let list = table
+            .entry(&key)
+            .or_insert_with(|| toml_edit::value(toml_edit::Array::new()));
+        if let Some(list) = list.as_array_mut() {
+            list.push(rejected);
+        }
+    })
+}
+
+fn part_key(prefix: &str, part: Part) -> String {
+    match part {
+        Part::One => format!("{}part_one", prefix),
+        Part::Two => format!("{}part_two", prefix),
+    }
+}
+
+/// Numbers are stored as TOML integers so they read the same as the answers already there.
+fn answer_value(answer: &str) -> toml_edit::Value {
+    match answer.parse::<i64>() {
+        Ok(number) => number.into(),
+        Err(_) => answer.into(),
+    }
+}
+
+fn edit_day(
+    path: &Path,
+    day: u8,
+    edit: impl FnOnce(&mut toml_edit::Table),
+) -> Result<(), AnswerStoreError> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(source) if source.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(source) => {
+            return Err(AnswerStoreError::Read {
+                path: path.to_path_buf(),
+                source,
+            })
+        }
+    };
+    let mut document: toml_edit::DocumentMut =
+        text.parse().map_err(|source| AnswerStoreError::Edit {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+    let day_item = document
+        .entry(&format!("day_{}", day))
+        .or_insert_with(toml_edit::table);
+    let Some(table) = day_item.as_table_mut() else {
+        return Err(AnswerStoreError::NotATable {
+            path: path.to_path_buf(),
+            day,
+        });
+    };
+    edit(table);
+
+    std::fs::write(path, document.to_string()).map_err(|source| AnswerStoreError::Write {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[derive(Debug)]
+pub enum AnswerStoreError {
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    Edit {
+        path: PathBuf,
+        source: toml_edit::TomlError,
+    },
+    /// `day_N` is something other than a table, so there is nowhere to record the answer.
+    NotATable { path: PathBuf, day: u8 },
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+impl fmt::Display for AnswerStoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswerStoreError::Read { path, source } => {
+                write!(
+                    f,
+                    "could not read answers file `{}`: {}",
+                    path.display(),
+                    source
+                )
+            }
+            AnswerStoreError::Parse { path, source } => {
+                write!(
+                    f,
+                    "answers file `{}` is invalid: {}",
+                    path.display(),
+                    source
+                )
+            }
+            AnswerStoreError::Edit { path, source } => {
+                write!(
+                    f,
+                    "answers file `{}` is invalid: {}",
+                    path.display(),
+                    source
+                )
+            }
+            AnswerStoreError::NotATable { path, day } => {
+                write!(
+                    f,
+                    "`day_{}` in answers file `{}` is not a table",
+                    day,
+                    path.display()
+                )
+            }
+            AnswerStoreError::Write { path, source } => {
+                write!(
+                    f,
+                    "could not write answers file `{}`: {}",
+                    path.display(),
+                    source
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswerStoreError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AnswerStoreError::Read { source, .. } | AnswerStoreError::Write { source, .. } => {
+                Some(source)
+            }
+            AnswerStoreError::Parse { source, .. } => Some(source),
+            AnswerStoreError::Edit { source, .. } => Some(source),
+            AnswerStoreError::NotATable { .. } => None,
+        }
+    }
+}
+
+#[test]
+fn test_check_numbers_and_text() {
+    let store = AnswerStore::parse(
+        "[day_5]
+part_one = \"CMZ\"
+
+[day_10]
+part_one = 13140
+part_two = '''
+##..
+..##
+'''",
+    )
+    .unwrap();
+
+    assert_eq!(
+        store.check(5, Part::One, &Answer::Text("CMZ".to_string())),
+        CheckResult::Pass
+    );
+    assert_eq!(
+        store.check(5, Part::Two, &Answer::Text("MCD".to_string())),
+        CheckResult::Missing
+    );
+    assert_eq!(
+        store.check(10, Part::One, &Answer::Signed(13140)),
+        CheckResult::Pass
+    );
+    assert_eq!(
+        store.check(10, Part::One, &Answer::Signed(13141)),
+        CheckResult::Fail {
+            expected: "13140".to_string()
+        }
+    );
+    assert_eq!(
+        store.check(10, Part::Two, &Answer::Text("##..\n..##".to_string())),
+        CheckResult::Pass
+    );
+    assert_eq!(
+        store.check(1, Part::One, &Answer::Unsigned(1)),
+        CheckResult::Missing
+    );
+}
+
+#[test]
+fn test_repo_answers_file_is_valid() {
+    let store = AnswerStore::load(Path::new("./answers.toml")).unwrap();
+
+    assert_eq!(store.expected(1, Part::One), Some("69626".to_string()));
+    assert!(store
+        .expected(10, Part::Two)
+        .unwrap()
+        .starts_with("###...##"));
+}
+
+#[test]
+fn test_refusal_from_known_answers() {
+    let store = AnswerStore::parse(
+        "[day_1]
+part_one = 24000
+rejected_part_two = [
+    { answer = 50000, reason = \"too_high\" },
+    { answer = 40000, reason = \"too_low\" },
+    { answer = 45001, reason = \"wrong\" },
+]",
+    )
+    .unwrap();
+
+    assert_eq!(
+        store.refusal(1, Part::One, "24000"),
+        Some(Refusal::AlreadyCorrect)
+    );
+    assert_eq!(
+        store.refusal(1, Part::One, "24001"),
+        Some(Refusal::DiffersFromCorrect {
+            expected: "24000".to_string()
+        })
+    );
+    assert_eq!(
+        store.refusal(1, Part::Two, "45001"),
+        Some(Refusal::AlreadyRejected(Rejection::Wrong))
+    );
+    assert_eq!(
+        store.refusal(1, Part::Two, "60000"),
+        Some(Refusal::BeyondBound {
+            bound: "50000".to_string(),
+            reason: Rejection::TooHigh
+        })
+    );
+    assert_eq!(
+        store.refusal(1, Part::Two, "40000"),
+        Some(Refusal::AlreadyRejected(Rejection::TooLow))
+    );
+    assert_eq!(store.refusal(1, Part::Two, "45000"), None);
+    assert_eq!(store.refusal(2, Part::One, "15"), None);
+}
+
+#[test]
+fn test_recording_keeps_the_rest_of_the_file() {
+    let path = crate::fixtures::temp_dir("record_answers").join("answers.toml");
+    std::fs::write(&path, "# Known answers\n\n[day_5]\npart_one = \"CMZ\"\n").unwrap();
+
+    record_rejected(&path, 5, Part::Two, "MZC", Rejection::Wrong).unwrap();
+    record_correct(&path, 5, Part::Two, "MCD").unwrap();
+    record_rejected(&path, 6, Part::One, "8", Rejection::TooHigh).unwrap();
+    record_rejected(&path, 6, Part::One, "3", Rejection::TooLow).unwrap();
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    assert!(text.starts_with("# Known answers\n\n[day_5]\npart_one = \"CMZ\"\n"));
+    let store = AnswerStore::parse(&text).unwrap();
+    assert_eq!(store.expected(5, Part::Two), Some("MCD".to_string()));
+    assert_eq!(store.days["day_5"].rejected(Part::Two)[0].answer(), "MZC");
+    assert_eq!(
+        store.refusal(6, Part::One, "9"),
+        Some(Refusal::BeyondBound {
+            bound: "8".to_string(),
+            reason: Rejection::TooHigh
+        })
+    );
+    assert_eq!(
+        store.refusal(6, Part::One, "3"),
+        Some(Refusal::AlreadyRejected(Rejection::TooLow))
+    );
+}
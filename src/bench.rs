@@ -0,0 +1,345 @@
+use crate::error::Error;
+use crate::memory::{self, MemoryStats};
+use crate::solution::{Part, Runnable};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Summary of repeated timings of one stage. Everything is in nanoseconds so that reports can be
+/// saved as JSON and compared between machines and commits.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    pub mean_ns: u64,
+    pub median_ns: u64,
+    pub stddev_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+
+        let mut nanos: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+
+        let runs = nanos.len();
+        let mean = nanos.iter().map(|n| *n as f64).sum::<f64>() / runs as f64;
+        let variance = nanos
+            .iter()
+            .map(|n| (*n as f64 - mean).powi(2))
+            .sum::<f64>()
+            / runs as f64;
+        let median = if runs.is_multiple_of(2) {
+            (nanos[runs / 2 - 1] + nanos[runs / 2]) / 2
+        } else {
+            nanos[runs / 2]
+        };
+
+        Stats {
+            runs,
+            mean_ns: mean.round() as u64,
+            median_ns: median,
+            stddev_ns: variance.sqrt().round() as u64,
+            min_ns: nanos[0],
+            max_ns: nanos[runs - 1],
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "mean {:>10}  median {:>10}  stddev {:>10}",
+            format!("{:.2?}", Duration::from_nanos(self.mean_ns)),
+            format!("{:.2?}", Duration::from_nanos(self.median_ns)),
+            format!("{:.2?}", Duration::from_nanos(self.stddev_ns)),
+        )
+    }
+}
+
+/// The separately timed stages of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Stage {
+    pub fn for_part(part: Part) -> Self {
+        match part {
+            Part::One => Stage::PartOne,
+            Part::Two => Stage::PartTwo,
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Stage::Parse => "parse",
+            Stage::PartOne => "part one",
+            Stage::PartTwo => "part two",
+        };
+        f.pad(name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StageTiming {
+    pub stage: Stage,
+    pub stats: Stats,
+    /// What one more run allocated, when memory was measured too.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemoryStats>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub day: u8,
+    pub stages: Vec<StageTiming>,
+}
+
+impl DayBench {
+    pub fn stage(&self, stage: Stage) -> Option<&Stats> {
+        self.stages
+            .iter()
+            .find(|timing| timing.stage == stage)
+            .map(|timing| &timing.stats)
+    }
+}
+
+/// Times parsing and each requested part of a day `runs` times. Each part reuses a single parse
+/// of the input so that its timings don't include parsing. Fails without timing anything further
+/// as soon as parsing or a part returns an error.
+///
+/// With `measure_memory`, each stage is run once more afterwards to count its allocations. That
+/// needs [`memory::CountingAllocator`] to be the global allocator.
+pub fn bench_day(
+    solution: &dyn Runnable,
+    input: &str,
+    parts: &[Part],
+    runs: usize,
+    measure_memory: bool,
+) -> Result<DayBench, Error> {
+    let runs = runs.max(1);
+    let mut stages = vec![];
+    let parsed = solution.parse_erased(input)?;
+
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = solution.parse_erased(black_box(input));
+        samples.push(start.elapsed());
+        drop(black_box(parsed));
+    }
+    stages.push(StageTiming {
+        stage: Stage::Parse,
+        stats: Stats::from_samples(&samples),
+        memory: measure_memory
+            .then(|| memory::measure(|| solution.parse_erased(input)).1)
+            .flatten(),
+    });
+
+    for part in parts {
+        solution.solve(parsed.as_ref(), *part)?;
+        let mut samples = Vec::with_capacity(runs);
+        for _ in 0..runs {
+            let start = Instant::now();
+            let answer = solution.solve(black_box(parsed.as_ref()), *part);
+            samples.push(start.elapsed());
+            drop(black_box(answer));
+        }
+        stages.push(StageTiming {
+            stage: Stage::for_part(*part),
+            stats: Stats::from_samples(&samples),
+            memory: measure_memory
+                .then(|| memory::measure(|| solution.solve(parsed.as_ref(), *part)).1)
+                .flatten(),
+        });
+    }
+
+    Ok(DayBench {
+        day: solution.day(),
+        stages,
+    })
+}
+
+/// A full benchmark run, as written with `--json` or saved as a baseline.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub days: Vec<DayBench>,
+}
+
+impl BenchReport {
+    pub fn load(path: &Path) -> Result<Self, BaselineError> {
+        let text = std::fs::read_to_string(path).map_err(|source| BaselineError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        serde_json::from_str(&text).map_err(|source| BaselineError::Json {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        let json = serde_json::to_string_pretty(self).expect("bench reports always serialise");
+        std::fs::write(path, json + "\n").map_err(|source| BaselineError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn day(&self, day: u8) -> Option<&DayBench> {
+        self.days.iter().find(|bench| bench.day == day)
+    }
+}
+
+/// One stage's mean time compared with the same stage in a baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub stage: Stage,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+    /// `true` if the current mean is more than the allowed tolerance above the baseline.
+    pub slower: bool,
+}
+
+impl Comparison {
+    /// The change from the baseline as a percentage, positive when slower.
+    pub fn change_percent(&self) -> f64 {
+        (self.current_ns as f64 - self.baseline_ns as f64) / self.baseline_ns.max(1) as f64 * 100.0
+    }
+}
+
+/// Compares every stage in `current` that also appears in `baseline`. A stage is flagged as
+/// slower when its mean is more than `tolerance_percent` above the baseline mean.
+pub fn compare(current: &DayBench, baseline: &DayBench, tolerance_percent: f64) -> Vec<Comparison> {
+    current
+        .stages
+        .iter()
+        .filter_map(|timing| {
+            let baseline_stats = baseline.stage(timing.stage)?;
+            let limit = baseline_stats.mean_ns as f64 * (1.0 + tolerance_percent / 100.0);
+            Some(Comparison {
+                day: current.day,
+                stage: timing.stage,
+                baseline_ns: baseline_stats.mean_ns,
+                current_ns: timing.stats.mean_ns,
+                slower: timing.stats.mean_ns as f64 > limit,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub enum BaselineError {
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Json {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+impl fmt::Display for BaselineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BaselineError::Io { path, source } => {
+                write!(
+                    f,
+                    "could not access baseline `{}`: {}",
+                    path.display(),
+                    source
+                )
+            }
+            BaselineError::Json { path, source } => {
+                write!(f, "baseline `{}` is invalid: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BaselineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BaselineError::Io { source, .. } => Some(source),
+            BaselineError::Json { source, .. } => Some(source),
+        }
+    }
+}
+
+#[test]
+fn test_stats_from_samples() {
+    let samples = [10, 20, 30, 40].map(Duration::from_nanos);
+    let stats = Stats::from_samples(&samples);
+
+    assert_eq!(stats.runs, 4);
+    assert_eq!(stats.mean_ns, 25);
+    assert_eq!(stats.median_ns, 25);
+    assert_eq!(stats.stddev_ns, 11); // sqrt(125)
+    assert_eq!(stats.min_ns, 10);
+    assert_eq!(stats.max_ns, 40);
+}
+
+#[test]
+fn test_compare_flags_slowdowns_beyond_tolerance() {
+    let day = |parse_ns, part_one_ns| {
+        let stats = |mean_ns| Stats {
+            runs: 1,
+            mean_ns,
+            median_ns: mean_ns,
+            stddev_ns: 0,
+            min_ns: mean_ns,
+            max_ns: mean_ns,
+        };
+        DayBench {
+            day: 1,
+            stages: vec![
+                StageTiming {
+                    stage: Stage::Parse,
+                    stats: stats(parse_ns),
+                    memory: None,
+                },
+                StageTiming {
+                    stage: Stage::PartOne,
+                    stats: stats(part_one_ns),
+                    memory: None,
+                },
+            ],
+        }
+    };
+
+    let comparisons = compare(&day(105, 150), &day(100, 100), 10.0);
+
+    assert!(!comparisons[0].slower);
+    assert!(comparisons[1].slower);
+    assert_eq!(comparisons[1].change_percent(), 50.0);
+}
+
+#[test]
+fn test_bench_day_measures_memory() {
+    let solution = crate::days::get(1).unwrap();
+    let input = "1000\n2000\n\n3000\n";
+
+    let timed_only = bench_day(solution.as_ref(), input, &[Part::One], 1, false).unwrap();
+    assert!(timed_only
+        .stages
+        .iter()
+        .all(|timing| timing.memory.is_none()));
+
+    let measured = bench_day(solution.as_ref(), input, &[Part::One], 1, true).unwrap();
+    let parse = measured.stages[0].memory.unwrap();
+    assert!(parse.allocations > 0);
+    assert!(measured.stages[1].memory.is_some());
+}
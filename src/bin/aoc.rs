@@ -0,0 +1,1131 @@
+use aoc_2022::answers::{self, AnswerStore, Refusal};
+use aoc_2022::bench::{self, BenchReport};
+use aoc_2022::client::{self, Client, ClientError, Fetched, Verdict};
+use aoc_2022::days;
+use aoc_2022::input::{InputError, InputSource};
+use aoc_2022::memory::CountingAllocator;
+use aoc_2022::params::ParamOverrides;
+use aoc_2022::progress::{Budget, CancelFlag};
+use aoc_2022::report::{CheckStatus, PartRecord};
+use aoc_2022::runner::{self, DayRun};
+use aoc_2022::scaffold;
+use aoc_2022::solution::{Answer, Part, Runnable};
+use aoc_2022::visualize::{FrameSink, Gif, PpmFrames, Recorder, Terminal};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+/// Only counts anything while `bench --memory` is measuring a stage.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day with `all`
+    Run(RunArgs),
+    /// Time parsing and each part over repeated runs
+    Bench(BenchArgs),
+    /// Download puzzle inputs that aren't saved yet
+    Fetch(FetchArgs),
+    /// Submit an answer and record the outcome in the answers file
+    Submit(SubmitArgs),
+    /// Start a new day from the template and register it with the runner
+    New(NewArgs),
+    /// Watch a day's simulation in the terminal or save it as images
+    Visualize(VisualizeArgs),
+    /// Check that inputs are well formed without solving them
+    Validate(ValidateArgs),
+    /// Make up a random input for a day, for stress tests and benchmarks
+    Generate(GenerateArgs),
+    /// List the numbers each day takes from the puzzle text, which `--param` can change
+    Params(ParamsArgs),
+}
+
+/// Which days and parts to work on and where their input comes from.
+#[derive(Args)]
+struct Selection {
+    /// Day number or `all`
+    day: DaySelection,
+
+    /// Only solve this part (1 or 2)
+    #[arg(long, value_parser = parse_part)]
+    part: Option<Part>,
+
+    /// Input file, directory of `input_day_N.txt` files, or `-` for stdin
+    #[arg(long, default_value = "./inputs")]
+    input: String,
+}
+
+impl Selection {
+    fn solutions(&self) -> Result<(Vec<Box<dyn Runnable>>, InputSource), String> {
+        let solutions = match self.day {
+            DaySelection::All => days::all(),
+            DaySelection::Day(day) => match days::get(day) {
+                Some(solution) => vec![solution],
+                None => return Err(format!("Day {} has not been solved yet", day)),
+            },
+        };
+
+        let source = InputSource::from_arg(&self.input);
+        if solutions.len() > 1 && !source.serves_many_days() {
+            return Err(format!(
+                "Running more than one day needs a directory of inputs, not `{}`",
+                self.input
+            ));
+        }
+        Ok((solutions, source))
+    }
+
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::BOTH.to_vec(),
+        }
+    }
+}
+
+/// Changes to the numbers the days take from the puzzle text rather than the input.
+#[derive(Args)]
+struct ParamArgs {
+    /// Use VALUE for one of a day's parameters, e.g. `15.row=10`. Can be given more than once
+    #[arg(long = "param", value_name = "DAY.NAME=VALUE")]
+    params: Vec<String>,
+
+    /// File of parameters to use, with a `[day_N]` table per day [default: ./aoc.toml if there
+    /// is one]
+    #[arg(long)]
+    config: Option<PathBuf>,
+}
+
+impl ParamArgs {
+    /// The overrides from the config file, with any `--param`s on top.
+    fn overrides(&self) -> Result<ParamOverrides, String> {
+        let default_config = PathBuf::from("./aoc.toml");
+        let mut overrides = match &self.config {
+            Some(path) => ParamOverrides::load(path).map_err(|error| error.to_string())?,
+            None if default_config.exists() => {
+                ParamOverrides::load(&default_config).map_err(|error| error.to_string())?
+            }
+            None => ParamOverrides::default(),
+        };
+        for arg in &self.params {
+            overrides.add_arg(arg).map_err(|error| error.to_string())?;
+        }
+        Ok(overrides)
+    }
+
+    fn apply(&self, solutions: &mut [Box<dyn Runnable>]) -> Result<(), String> {
+        let overrides = self.overrides()?;
+        for solution in solutions {
+            overrides
+                .apply(solution.as_mut())
+                .map_err(|error| error.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Compare each answer against the known answers file
+    #[arg(long)]
+    check: bool,
+
+    /// File holding the known answers used by `--check`
+    #[arg(long, default_value = "./answers.toml")]
+    answers: PathBuf,
+
+    /// How to write the results
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// How many days to solve at once [default: the number of CPUs]
+    #[arg(long)]
+    jobs: Option<NonZeroUsize>,
+
+    /// Give up on any part still running after this many seconds
+    #[arg(long, value_name = "SECONDS")]
+    timeout: Option<f64>,
+
+    /// Report how far long-running parts have got to stderr every second
+    #[arg(long)]
+    progress: bool,
+
+    #[command(flatten)]
+    params: ParamArgs,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Readable text
+    Text,
+    /// One JSON object per part per line, with timings and an input hash
+    Json,
+    /// The same fields as `json` as CSV with a header row
+    Csv,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// How many times each stage is timed
+    #[arg(long, default_value_t = 10)]
+    runs: usize,
+
+    /// Print the report as JSON instead of a table
+    #[arg(long)]
+    json: bool,
+
+    /// Compare against a report saved with `--save-baseline`
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// Save this run's report so later runs can be compared against it
+    #[arg(long)]
+    save_baseline: Option<PathBuf>,
+
+    /// How far above the baseline mean (in percent) a stage can be before it is flagged
+    #[arg(long, default_value_t = 10.0)]
+    tolerance: f64,
+
+    /// Also count the allocations, bytes allocated and peak memory of each stage
+    #[arg(long)]
+    memory: bool,
+
+    #[command(flatten)]
+    params: ParamArgs,
+}
+
+/// How to reach the website.
+#[derive(Args)]
+struct WebsiteArgs {
+    /// File holding the session token, used when $AOC_SESSION isn't set
+    #[arg(long, default_value = "./.aoc_session")]
+    session_file: PathBuf,
+
+    /// Website to talk to
+    #[arg(long, default_value = client::BASE_URL)]
+    base_url: String,
+}
+
+impl WebsiteArgs {
+    fn client(&self) -> Result<Client, ClientError> {
+        let session = client::load_session(&self.session_file)?;
+        Ok(Client::new(session).with_base_url(&self.base_url))
+    }
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Day number or `all`
+    day: DaySelection,
+
+    /// Directory the `input_day_N.txt` files are saved in
+    #[arg(long, default_value = "./inputs")]
+    inputs: PathBuf,
+
+    #[command(flatten)]
+    website: WebsiteArgs,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day number
+    #[arg(value_parser = parse_day)]
+    day: u8,
+
+    /// Part to submit (1 or 2)
+    #[arg(value_parser = parse_part)]
+    part: Part,
+
+    /// Answer to submit. Worked out by solving the day if left out
+    answer: Option<String>,
+
+    /// Input file, or directory of `input_day_N.txt` files, used to work out the answer
+    #[arg(long, default_value = "./inputs")]
+    input: String,
+
+    /// File the outcome is recorded in
+    #[arg(long, default_value = "./answers.toml")]
+    answers: PathBuf,
+
+    /// When told to wait before answering again, wait and then submit once more
+    #[arg(long)]
+    wait: bool,
+
+    #[command(flatten)]
+    website: WebsiteArgs,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day number
+    #[arg(value_parser = parse_day)]
+    day: u8,
+
+    /// Root of the repository
+    #[arg(long, default_value = ".")]
+    root: PathBuf,
+}
+
+#[derive(Args)]
+struct VisualizeArgs {
+    /// Day number
+    #[arg(value_parser = parse_day)]
+    day: u8,
+
+    /// Part whose simulation is shown (1 or 2)
+    #[arg(long, value_parser = parse_part, default_value = "1")]
+    part: Part,
+
+    /// Input file, or directory of `input_day_N.txt` files
+    #[arg(long, default_value = "./inputs")]
+    input: String,
+
+    /// Frames per second, for both playback and GIFs
+    #[arg(long, default_value_t = 20.0)]
+    fps: f64,
+
+    /// Only show every Nth step of the simulation
+    #[arg(long, default_value_t = 1)]
+    every: usize,
+
+    /// Stop after this many frames
+    #[arg(long)]
+    frames: Option<usize>,
+
+    /// Save the frames instead of playing them: a `.gif` file, or a directory for PPM images
+    #[arg(long)]
+    output: Option<PathBuf>,
+
+    /// Size in pixels of each cell in saved images
+    #[arg(long, default_value_t = 4)]
+    scale: usize,
+
+    #[command(flatten)]
+    params: ParamArgs,
+}
+
+#[derive(Args)]
+struct ValidateArgs {
+    /// Day number or `all`
+    day: DaySelection,
+
+    /// Input file, directory of `input_day_N.txt` files, or `-` for stdin
+    #[arg(default_value = "./inputs")]
+    input: String,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// Day number
+    #[arg(value_parser = parse_day)]
+    day: u8,
+
+    /// The same seed always makes the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// How big an input to make, in whatever the day counts (lines, monkeys, sensors, ...)
+    #[arg(long, default_value_t = 100)]
+    size: usize,
+
+    /// Write the input to this file instead of printing it
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct ParamsArgs {
+    /// Day number or `all`
+    #[arg(default_value = "all")]
+    day: DaySelection,
+
+    #[command(flatten)]
+    params: ParamArgs,
+}
+
+#[derive(Clone, Copy)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+impl std::str::FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(DaySelection::All);
+        }
+        parse_day(s)
+            .map(DaySelection::Day)
+            .map_err(|_| format!("expected a day between 1 and 25 or `all`, got `{}`", s))
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("expected a day between 1 and 25, got `{}`", s)),
+    }
+}
+
+fn parse_part(s: &str) -> Result<Part, String> {
+    s.parse()
+        .ok()
+        .and_then(Part::from_number)
+        .ok_or_else(|| format!("expected 1 or 2, got `{}`", s))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::New(args) => new(args),
+        Command::Visualize(args) => visualize(args),
+        Command::Validate(args) => validate(args),
+        Command::Generate(args) => generate(args),
+        Command::Params(args) => params(args),
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let (mut solutions, source) = match args.selection.solutions() {
+        Ok(selected) => selected,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(message) = args.params.apply(&mut solutions) {
+        eprintln!("{}", message);
+        return ExitCode::FAILURE;
+    }
+    let parts = args.selection.parts();
+    let answer_store = if args.check {
+        match AnswerStore::load(&args.answers) {
+            Ok(store) => Some(store),
+            Err(error) => {
+                eprintln!("{}", error);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        None
+    };
+
+    let budget = match run_budget(&args) {
+        Ok(budget) => budget,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let format = args.format;
+    if format == Format::Csv {
+        println!("{}", PartRecord::CSV_HEADER);
+    }
+    let jobs = args
+        .jobs
+        .or_else(|| std::thread::available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get);
+
+    let start = Instant::now();
+    let mut day_runs = vec![];
+    let mut exit_code = ExitCode::SUCCESS;
+    runner::run_in_order(
+        &solutions,
+        jobs,
+        |solution| {
+            let input_text = source.read(solution.day())?;
+            Ok(runner::run_day(
+                solution.as_ref(),
+                &input_text,
+                &parts,
+                answer_store.as_ref(),
+                &budget,
+            ))
+        },
+        |solution, result: Result<DayRun, InputError>| match result {
+            Ok(day_run) => {
+                match format {
+                    Format::Text => print_day_run(&day_run),
+                    Format::Json | Format::Csv => day_run
+                        .records
+                        .iter()
+                        .for_each(|record| print_record(format, record)),
+                }
+                day_runs.push(day_run);
+            }
+            Err(error) => {
+                eprintln!("Day {}: {}", solution.day(), error);
+                exit_code = ExitCode::FAILURE;
+            }
+        },
+    );
+    let elapsed = start.elapsed();
+
+    let records: Vec<&PartRecord> = day_runs
+        .iter()
+        .flat_map(|day_run| &day_run.records)
+        .collect();
+    if format == Format::Text && day_runs.len() > 1 {
+        print_summary_table(&records);
+        println!(
+            "{} days in {:.2?} with up to {} at once",
+            day_runs.len(),
+            elapsed,
+            jobs.min(day_runs.len())
+        );
+    }
+
+    let mut tally = CheckTally::default();
+    records.iter().for_each(|record| tally.add(record));
+    if tally.errors > 0 {
+        exit_code = ExitCode::FAILURE;
+    }
+    if answer_store.is_some() {
+        let summary = format!(
+            "{} passed, {} failed, {} missing",
+            tally.passed,
+            tally.failed + tally.errors,
+            tally.missing
+        );
+        // Kept off stdout when it's meant to be read by another program
+        if format == Format::Text {
+            println!("{}", summary);
+        } else {
+            eprintln!("{}", summary);
+        }
+        if tally.failed > 0 {
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+    exit_code
+}
+
+/// The limits `run` solves each part under. The first Ctrl-C cancels whatever is still running so
+/// the results so far are still printed, a second one exits straight away.
+fn run_budget(args: &RunArgs) -> Result<Budget, String> {
+    let mut budget = Budget::new();
+    if let Some(timeout) = args.timeout {
+        if !(timeout > 0.0 && timeout.is_finite()) {
+            return Err("--timeout has to be more than 0".to_string());
+        }
+        budget = budget.with_timeout(Duration::from_secs_f64(timeout));
+    }
+    if args.progress {
+        budget = budget.with_progress(Duration::from_secs(1), |progress| eprintln!("{}", progress));
+    }
+
+    let cancel = CancelFlag::new();
+    let handler_flag = cancel.clone();
+    let handler = ctrlc::set_handler(move || {
+        if handler_flag.is_cancelled() {
+            std::process::exit(130);
+        }
+        eprintln!("Cancelling, press Ctrl-C again to quit now");
+        handler_flag.cancel();
+    });
+    if let Err(error) = handler {
+        return Err(format!("Couldn't listen for Ctrl-C: {}", error));
+    }
+    Ok(budget.with_cancel_flag(cancel))
+}
+
+fn bench(args: BenchArgs) -> ExitCode {
+    let (mut solutions, source) = match args.selection.solutions() {
+        Ok(selected) => selected,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(message) = args.params.apply(&mut solutions) {
+        eprintln!("{}", message);
+        return ExitCode::FAILURE;
+    }
+    let parts = args.selection.parts();
+    let baseline = match &args.baseline {
+        Some(path) => match BenchReport::load(path) {
+            Ok(baseline) => Some(baseline),
+            Err(error) => {
+                eprintln!("{}", error);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+
+    let mut report = BenchReport::default();
+    let mut exit_code = ExitCode::SUCCESS;
+    for solution in solutions {
+        let day = solution.day();
+        let input_text = match source.read(day) {
+            Ok(input_text) => input_text,
+            Err(error) => {
+                eprintln!("Day {}: {}", day, error);
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let day_bench = match bench::bench_day(
+            solution.as_ref(),
+            &input_text,
+            &parts,
+            args.runs,
+            args.memory,
+        ) {
+            Ok(day_bench) => day_bench,
+            Err(error) => {
+                eprintln!("Day {}: {}", day, error);
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        if !args.json {
+            println!("Day {} ({} runs)", day, args.runs.max(1));
+            for timing in &day_bench.stages {
+                println!("  {:<9} {}", timing.stage, timing.stats);
+                if let Some(memory) = &timing.memory {
+                    println!("  {:<9} {}", "", memory);
+                }
+            }
+        }
+        report.days.push(day_bench);
+    }
+
+    if args.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report).expect("bench reports always serialise")
+        );
+    }
+
+    if let Some(baseline) = &baseline {
+        let mut num_slower = 0;
+        // Comparisons go to stderr so they don't get mixed into `--json` output
+        eprintln!("Compared with baseline (tolerance {}%):", args.tolerance);
+        for day_bench in &report.days {
+            let Some(baseline_day) = baseline.day(day_bench.day) else {
+                eprintln!("  Day {}: not in baseline", day_bench.day);
+                continue;
+            };
+            for comparison in bench::compare(day_bench, baseline_day, args.tolerance) {
+                eprintln!(
+                    "  Day {} {:<9} {:>10} -> {:>10} ({:+.1}%){}",
+                    comparison.day,
+                    comparison.stage,
+                    format!("{:.2?}", Duration::from_nanos(comparison.baseline_ns)),
+                    format!("{:.2?}", Duration::from_nanos(comparison.current_ns)),
+                    comparison.change_percent(),
+                    if comparison.slower { "  SLOWER" } else { "" }
+                );
+                if comparison.slower {
+                    num_slower += 1;
+                }
+            }
+        }
+        if num_slower > 0 {
+            eprintln!("{} stage(s) slower than the baseline", num_slower);
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+
+    if let Some(path) = &args.save_baseline {
+        if let Err(error) = report.save(path) {
+            eprintln!("{}", error);
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+    exit_code
+}
+
+fn fetch(args: FetchArgs) -> ExitCode {
+    let days: Vec<u8> = match args.day {
+        DaySelection::All => (1..=25).collect(),
+        DaySelection::Day(day) => vec![day],
+    };
+    let source = InputSource::Directory(args.inputs.clone());
+
+    for day in days {
+        let path = source
+            .path_for_day(day)
+            .expect("directories have a path for every day");
+        match client::cache_input(|| args.website.client(), day, &path) {
+            Ok(Fetched::Downloaded) => println!("Day {}: saved to `{}`", day, path.display()),
+            Ok(Fetched::AlreadyCached) => {
+                println!("Day {}: already saved at `{}`", day, path.display())
+            }
+            // Whatever went wrong (usually the session) would most likely fail every other day
+            // the same way, so there's no point carrying on
+            Err(error) => {
+                eprintln!("Day {}: {}", day, error);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn submit(args: SubmitArgs) -> ExitCode {
+    let (day, part) = (args.day, args.part);
+    let answer = match &args.answer {
+        Some(answer) => answer.trim().to_string(),
+        None => match solve_one(day, part, &args.input) {
+            Ok(answer) => answer.to_string(),
+            Err(message) => {
+                eprintln!("Day {}: {}", day, message);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+    if answer.is_empty() || answer.contains('\n') {
+        eprintln!(
+            "Day {} {}: only single-line answers can be submitted, pass the answer in instead",
+            day, part
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let store = match AnswerStore::load(&args.answers) {
+        Ok(store) => store,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(refusal) = store.refusal(day, part, &answer) {
+        println!(
+            "Day {} {}: not submitting {}, {}",
+            day, part, answer, refusal
+        );
+        return if refusal == Refusal::AlreadyCorrect {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        };
+    }
+
+    let client = match args.website.client() {
+        Ok(client) => client,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut may_wait = args.wait;
+    let verdict = loop {
+        match client.submit(day, part, &answer) {
+            Ok(Verdict::Wait(duration)) if may_wait => {
+                println!("Waiting {}s before submitting", duration.as_secs());
+                std::thread::sleep(duration);
+                may_wait = false;
+            }
+            Ok(verdict) => break verdict,
+            Err(error) => {
+                eprintln!("{}", error);
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+
+    let recorded = match &verdict {
+        Verdict::Correct => {
+            println!("Day {} {}: {} is correct", day, part, answer);
+            answers::record_correct(&args.answers, day, part, &answer)
+        }
+        Verdict::Incorrect(reason) => {
+            println!("Day {} {}: {} is {}", day, part, answer, reason);
+            answers::record_rejected(&args.answers, day, part, &answer, *reason)
+        }
+        Verdict::Wait(duration) => {
+            println!(
+                "Day {} {}: answered too recently, try again in {}s or pass --wait",
+                day,
+                part,
+                duration.as_secs()
+            );
+            Ok(())
+        }
+        Verdict::AlreadySolved => {
+            println!(
+                "Day {} {}: already solved, its answer can be added to `{}` by hand",
+                day,
+                part,
+                args.answers.display()
+            );
+            Ok(())
+        }
+        Verdict::Unrecognised(message) => {
+            println!("Day {} {}: unrecognised response: {}", day, part, message);
+            Ok(())
+        }
+    };
+    if let Err(error) = recorded {
+        eprintln!("{}", error);
+        return ExitCode::FAILURE;
+    }
+
+    if verdict == Verdict::Correct {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn new(args: NewArgs) -> ExitCode {
+    match scaffold::new_day(&args.root, args.day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("Wrote `{}`", path.display());
+            }
+            println!(
+                "Next, add the puzzle's example and its answers to `inputs/tests/day_{0}/` and \
+                 fetch the input with `aoc fetch {0}`",
+                args.day
+            );
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn visualize(args: VisualizeArgs) -> ExitCode {
+    let Some(mut visualization) = days::visualizations()
+        .into_iter()
+        .find(|visualization| visualization.day() == args.day)
+    else {
+        let days: Vec<String> = days::visualizations()
+            .iter()
+            .map(|visualization| visualization.day().to_string())
+            .collect();
+        eprintln!(
+            "Day {} has no visualization, only days {} do",
+            args.day,
+            days.join(", ")
+        );
+        return ExitCode::FAILURE;
+    };
+    if let Err(message) = args.params.overrides().and_then(|overrides| {
+        overrides
+            .apply(visualization.as_mut())
+            .map_err(|error| error.to_string())
+    }) {
+        eprintln!("{}", message);
+        return ExitCode::FAILURE;
+    }
+    if !(args.fps > 0.0 && args.fps.is_finite()) {
+        eprintln!("--fps has to be more than 0");
+        return ExitCode::FAILURE;
+    }
+    let delay = Duration::from_secs_f64(1.0 / args.fps);
+
+    let parsed = match InputSource::from_arg(&args.input)
+        .read(args.day)
+        .map_err(aoc_2022::error::Error::from)
+        .and_then(|input_text| visualization.parse_erased(&input_text))
+    {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("Day {}: {}", args.day, error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let sink: std::io::Result<Box<dyn FrameSink>> = match &args.output {
+        None => Ok(Box::new(Terminal::new(std::io::stdout().lock(), delay))),
+        Some(path) if path.extension().is_some_and(|extension| extension == "gif") => {
+            std::fs::File::create(path).map(|file| {
+                Box::new(Gif::new(std::io::BufWriter::new(file), args.scale, delay))
+                    as Box<dyn FrameSink>
+            })
+        }
+        Some(path) => {
+            PpmFrames::new(path, args.scale).map(|frames| Box::new(frames) as Box<dyn FrameSink>)
+        }
+    };
+    let mut sink = match sink {
+        Ok(sink) => sink,
+        Err(error) => {
+            eprintln!(
+                "Could not write to `{}`: {}",
+                args.output.unwrap().display(),
+                error
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut recorder = Recorder::new(sink.as_mut(), args.every, args.frames);
+    if let Err(error) = visualization.visualize_erased(parsed.as_ref(), args.part, &mut recorder) {
+        eprintln!("Day {}: {}", args.day, error);
+        return ExitCode::FAILURE;
+    }
+    match recorder.finish() {
+        Ok(num_frames) => {
+            if let Some(path) = &args.output {
+                println!("Wrote {} frames to `{}`", num_frames, path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Could not write frames: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn validate(args: ValidateArgs) -> ExitCode {
+    let selection = Selection {
+        day: args.day,
+        part: None,
+        input: args.input,
+    };
+    let (solutions, source) = match selection.solutions() {
+        Ok(selected) => selected,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+    for solution in solutions {
+        let day = solution.day();
+        let input_text = match source.read(day) {
+            Ok(input_text) => input_text,
+            Err(error) => {
+                eprintln!("Day {}: {}", day, error);
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let problems = solution.validate_input(&input_text);
+        match problems.len() {
+            0 => println!("Day {}: OK", day),
+            1 => println!("Day {}: 1 problem", day),
+            num_problems => println!("Day {}: {} problems", day, num_problems),
+        }
+        for problem in &problems {
+            println!("  {}", problem);
+        }
+        if !problems.is_empty() {
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+    exit_code
+}
+
+fn generate(args: GenerateArgs) -> ExitCode {
+    let Some(generator) = days::generators()
+        .into_iter()
+        .find(|generator| generator.day() == args.day)
+    else {
+        eprintln!("Day {} has not been solved yet", args.day);
+        return ExitCode::FAILURE;
+    };
+
+    let input_text = generator.generate_input(args.seed, args.size);
+    match &args.output {
+        None => {
+            println!("{}", input_text);
+            ExitCode::SUCCESS
+        }
+        Some(path) => match std::fs::write(path, input_text + "\n") {
+            Ok(()) => {
+                println!("Wrote day {} input to `{}`", args.day, path.display());
+                ExitCode::SUCCESS
+            }
+            Err(error) => {
+                eprintln!("Could not write to `{}`: {}", path.display(), error);
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+fn params(args: ParamsArgs) -> ExitCode {
+    let mut solutions = match args.day {
+        DaySelection::All => days::all(),
+        DaySelection::Day(day) => match days::get(day) {
+            Some(solution) => vec![solution],
+            None => {
+                eprintln!("Day {} has not been solved yet", day);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+    if let Err(message) = args.params.apply(&mut solutions) {
+        eprintln!("{}", message);
+        return ExitCode::FAILURE;
+    }
+
+    for solution in &solutions {
+        let params = solution.current_params();
+        if params.is_empty() {
+            if let DaySelection::Day(day) = args.day {
+                println!("Day {} has no parameters", day);
+            }
+            continue;
+        }
+        println!("Day {}", solution.day());
+        let width = params
+            .iter()
+            .map(|param| param.to_string().len())
+            .max()
+            .unwrap_or(0);
+        for param in params {
+            println!(
+                "  {:<width$}  {}",
+                param.to_string(),
+                param.description,
+                width = width
+            );
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Solves one part of `day` with the input from `input` (a file or a directory of inputs).
+fn solve_one(day: u8, part: Part, input: &str) -> Result<Answer, String> {
+    let solution = days::get(day).ok_or("this day has not been solved yet")?;
+    let input_text = InputSource::from_arg(input)
+        .read(day)
+        .map_err(|error| error.to_string())?;
+    let parsed = solution
+        .parse_erased(&input_text)
+        .map_err(|error| error.to_string())?;
+    solution
+        .solve(parsed.as_ref(), part)
+        .map_err(|error| error.to_string())
+}
+
+/// Writes `record` in the machine-readable formats. Text is printed as each part is solved.
+fn print_record(format: Format, record: &PartRecord) {
+    match format {
+        Format::Text => {}
+        Format::Json => println!("{}", record.to_json()),
+        Format::Csv => println!("{}", record.to_csv()),
+    }
+}
+
+/// Multi-line answers (day 10's CRT) are started on their own line so the render lines up.
+fn format_answer(answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("\n{}", answer)
+    } else {
+        format!(" {}", answer)
+    }
+}
+
+fn print_day_run(day_run: &DayRun) {
+    println!("Day {}", day_run.day);
+    if let Some(error) = &day_run.parse_error {
+        println!("  error: {}", error);
+        return;
+    }
+
+    for record in &day_run.records {
+        let part = Part::from_number(record.part).expect("records only hold parts one and two");
+        let answer = record.answer.as_deref().unwrap_or_default();
+        match (&record.error, record.check) {
+            (Some(error), _) => println!("  {}: error: {}", part, error),
+            (None, None) => println!("  {}:{}", part, format_answer(answer)),
+            (None, Some(CheckStatus::Pass)) => {
+                println!("  {} [PASS]:{}", part, format_answer(answer))
+            }
+            (None, Some(CheckStatus::Missing)) => {
+                println!("  {} [MISSING]:{}", part, format_answer(answer))
+            }
+            (None, Some(CheckStatus::Fail)) => {
+                let expected = record.expected.as_deref().unwrap_or_default();
+                println!("  {} [FAIL]", part);
+                println!("    expected:{}", format_answer(expected));
+                println!("    actual:{}", format_answer(answer));
+            }
+        }
+    }
+}
+
+/// One row per part with its answer, timings and outcome. Failures are in capitals so they stand
+/// out from the rest.
+fn print_summary_table(records: &[&PartRecord]) {
+    println!();
+    println!(
+        "{:>3}  {:<4}  {:<20}  {:>10}  {:>10}  Result",
+        "Day", "Part", "Answer", "Parse", "Solve"
+    );
+    for record in records {
+        let answer = match &record.answer {
+            Some(answer) if answer.contains('\n') => {
+                format!("({} lines)", answer.lines().count())
+            }
+            Some(answer) => answer.clone(),
+            None => "-".to_string(),
+        };
+        let result = match (&record.error, record.check) {
+            (Some(_), _) => "ERROR",
+            (None, Some(CheckStatus::Fail)) => "FAIL",
+            (None, Some(CheckStatus::Pass)) => "pass",
+            (None, Some(CheckStatus::Missing)) => "missing",
+            (None, None) => "ok",
+        };
+        let format_ns = |ns: u64| format!("{:.2?}", Duration::from_nanos(ns));
+        println!(
+            "{:>3}  {:<4}  {:<20}  {:>10}  {:>10}  {}",
+            record.day,
+            record.part,
+            answer,
+            format_ns(record.parse_ns),
+            record.solve_ns.map_or("-".to_string(), format_ns),
+            result
+        );
+    }
+}
+
+#[derive(Default)]
+struct CheckTally {
+    passed: u32,
+    failed: u32,
+    missing: u32,
+    /// Parts that couldn't be solved at all.
+    errors: u32,
+}
+
+impl CheckTally {
+    fn add(&mut self, record: &PartRecord) {
+        match (&record.error, record.check) {
+            (Some(_), _) => self.errors += 1,
+            (None, Some(CheckStatus::Pass)) => self.passed += 1,
+            (None, Some(CheckStatus::Fail)) => self.failed += 1,
+            (None, Some(CheckStatus::Missing)) => self.missing += 1,
+            (None, None) => {}
+        }
+    }
+}
@@ -0,0 +1,475 @@
+use crate::answers::Rejection;
+use crate::solution::Part;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+const YEAR: u16 = 2022;
+const USER_AGENT: &str = concat!("aoc_2022/", env!("CARGO_PKG_VERSION"));
+
+/// Environment variable holding the session token. It takes precedence over the session file.
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+/// Talks to the Advent of Code website (or a stand-in for it) using a logged in session.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(session: impl Into<String>) -> Self {
+        Client {
+            base_url: BASE_URL.to_string(),
+            session: session.into(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+
+    /// Sends requests somewhere other than adventofcode.com, e.g. a local test server.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    /// Downloads the puzzle input for `day`.
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        let url = format!("{}/input", self.day_url(day));
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|error| ClientError::from_ureq(&url, error))?;
+
+        response
+            .into_string()
+            .map_err(|source| ClientError::Body { url, source })
+    }
+
+    /// Submits `answer` for one part of `day` and reports what the website made of it.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict, ClientError> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = part.number().to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &level), ("answer", answer)])
+            .map_err(|error| ClientError::from_ureq(&url, error))?;
+
+        let page = response
+            .into_string()
+            .map_err(|source| ClientError::Body { url, source })?;
+        Ok(Verdict::from_page(&page))
+    }
+}
+
+/// What the website said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect(Rejection),
+    /// An answer was submitted too recently and another can't be sent until this has passed.
+    Wait(Duration),
+    /// The part has already been solved, so the website doesn't check answers for it any more.
+    AlreadySolved,
+    /// The response didn't match any of the messages above. Holds the message text.
+    Unrecognised(String),
+}
+
+impl Verdict {
+    /// Works out the verdict from the `<article>` of the page returned after submitting.
+    fn from_page(page: &str) -> Self {
+        let message = page_message(page);
+
+        if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            Verdict::Incorrect(if message.contains("your answer is too high") {
+                Rejection::TooHigh
+            } else if message.contains("your answer is too low") {
+                Rejection::TooLow
+            } else {
+                Rejection::Wrong
+            })
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::Wait(parse_wait(&message).unwrap_or(Duration::from_secs(60)))
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unrecognised(message)
+        }
+    }
+}
+
+/// The text of the page's `<article>` (or the whole page if there isn't one) without its tags
+/// and with runs of whitespace collapsed.
+fn page_message(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map_or(page, |(_, rest)| {
+            rest.split_once("</article>").map_or(rest, |(body, _)| body)
+        });
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads the time in `You have 1m 30s left to wait`.
+fn parse_wait(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (time, _) = rest.split_once(" left to wait")?;
+
+    let mut seconds = 0;
+    for amount in time.split_whitespace() {
+        seconds += match amount.split_at(amount.len().checked_sub(1)?) {
+            (number, "h") => number.parse::<u64>().ok()? * 3600,
+            (number, "m") => number.parse::<u64>().ok()? * 60,
+            (number, "s") => number.parse::<u64>().ok()?,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Reads the session token from `$AOC_SESSION` or, if that isn't set, from `session_file`.
+pub fn load_session(session_file: &Path) -> Result<String, ClientError> {
+    session_from(std::env::var(SESSION_ENV_VAR).ok(), session_file)
+}
+
+fn session_from(env_value: Option<String>, session_file: &Path) -> Result<String, ClientError> {
+    let token = match env_value {
+        Some(token) => token,
+        None => std::fs::read_to_string(session_file).map_err(|source| {
+            if source.kind() == io::ErrorKind::NotFound {
+                ClientError::NoSession(session_file.to_path_buf())
+            } else {
+                ClientError::SessionFile {
+                    path: session_file.to_path_buf(),
+                    source,
+                }
+            }
+        })?,
+    };
+
+    let token = token.trim();
+    if token.is_empty() {
+        return Err(ClientError::NoSession(session_file.to_path_buf()));
+    }
+    Ok(token.to_string())
+}
+
+/// Whether [`cache_input`] had to download the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    AlreadyCached,
+}
+
+/// Downloads the input for `day` to `path` unless the file already exists. An existing file is
+/// never downloaded again, so it is safe to run for every day repeatedly. The session is only
+/// needed (and `client` only created) when something has to be downloaded.
+pub fn cache_input(
+    client: impl FnOnce() -> Result<Client, ClientError>,
+    day: u8,
+    path: &Path,
+) -> Result<Fetched, ClientError> {
+    if path.exists() {
+        return Ok(Fetched::AlreadyCached);
+    }
+
+    let input = client()?.input(day)?;
+    let write_error = |source| ClientError::Write {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(write_error)?;
+    }
+    // Written next to the real file and then moved into place, so an interrupted download
+    // doesn't leave a partial input that would then count as cached
+    let partial_path = path.with_extension("partial");
+    std::fs::write(&partial_path, input).map_err(write_error)?;
+    std::fs::rename(&partial_path, path).map_err(write_error)?;
+
+    Ok(Fetched::Downloaded)
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// Neither `$AOC_SESSION` nor the session file hold a token.
+    NoSession(PathBuf),
+    SessionFile {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// The server answered with an error status.
+    Status {
+        url: String,
+        status: u16,
+    },
+    /// The server couldn't be reached.
+    Transport {
+        url: String,
+        message: String,
+    },
+    Body {
+        url: String,
+        source: io::Error,
+    },
+    Write {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl ClientError {
+    fn from_ureq(url: &str, error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Status(status, _) => ClientError::Status {
+                url: url.to_string(),
+                status,
+            },
+            ureq::Error::Transport(transport) => ClientError::Transport {
+                url: url.to_string(),
+                message: transport.to_string(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::NoSession(path) => write!(
+                f,
+                "no session token: set ${} or put it in `{}`",
+                SESSION_ENV_VAR,
+                path.display()
+            ),
+            ClientError::SessionFile { path, source } => {
+                write!(
+                    f,
+                    "could not read session file `{}`: {}",
+                    path.display(),
+                    source
+                )
+            }
+            ClientError::Status { url, status } => {
+                write!(f, "`{}` returned status {}", url, status)?;
+                match status {
+                    400 | 500 => write!(f, " (the session token may have expired)"),
+                    404 => write!(f, " (the puzzle may not be unlocked yet)"),
+                    _ => Ok(()),
+                }
+            }
+            // ureq's message already names the URL
+            ClientError::Transport { message, .. } => write!(f, "request failed: {}", message),
+            ClientError::Body { url, source } => {
+                write!(f, "could not read the response from `{}`: {}", url, source)
+            }
+            ClientError::Write { path, source } => {
+                write!(f, "could not write `{}`: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::SessionFile { source, .. }
+            | ClientError::Body { source, .. }
+            | ClientError::Write { source, .. } => Some(source),
+            ClientError::NoSession(_)
+            | ClientError::Status { .. }
+            | ClientError::Transport { .. } => None,
+        }
+    }
+}
+
+/// A request received by [`serve`].
+#[cfg(test)]
+#[derive(Debug)]
+struct Received {
+    method: String,
+    url: String,
+    cookie: Option<String>,
+    body: String,
+}
+
+/// Starts a stand-in for the website that answers the next `responses.len()` requests in order
+/// and then stops. Returns its base URL and a handle that gives back the requests it received.
+#[cfg(test)]
+fn serve(responses: Vec<(u16, &'static str)>) -> (String, std::thread::JoinHandle<Vec<Received>>) {
+    let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+
+    let handle = std::thread::spawn(move || {
+        let mut received = vec![];
+        for (status, body) in responses {
+            let mut request = server.recv().unwrap();
+            let mut request_body = String::new();
+            std::io::Read::read_to_string(request.as_reader(), &mut request_body).unwrap();
+            received.push(Received {
+                method: request.method().to_string(),
+                url: request.url().to_string(),
+                cookie: request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string()),
+                body: request_body,
+            });
+            request
+                .respond(tiny_http::Response::from_string(body).with_status_code(status))
+                .unwrap();
+        }
+        received
+    });
+    (base_url, handle)
+}
+
+#[test]
+fn test_cache_input_downloads_once() {
+    let (base_url, server) = serve(vec![(200, "1000\n2000\n")]);
+    let path = crate::fixtures::temp_dir("cache_input")
+        .join("inputs")
+        .join("input_day_1.txt");
+    let client = || Ok(Client::new("abc123").with_base_url(&base_url));
+
+    assert_eq!(cache_input(client, 1, &path).unwrap(), Fetched::Downloaded);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+    // The stand-in only answers one request, so this would fail if it downloaded again
+    assert_eq!(
+        cache_input(client, 1, &path).unwrap(),
+        Fetched::AlreadyCached
+    );
+
+    let received = server.join().unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].method, "GET");
+    assert_eq!(received[0].url, "/2022/day/1/input");
+    assert_eq!(received[0].cookie.as_deref(), Some("session=abc123"));
+}
+
+#[test]
+fn test_cached_input_needs_no_session() {
+    let path = crate::fixtures::temp_dir("cached_input").join("input_day_2.txt");
+    std::fs::write(&path, "A Y\n").unwrap();
+
+    let result = cache_input(|| Err(ClientError::NoSession("unused".into())), 2, &path);
+
+    assert_eq!(result.unwrap(), Fetched::AlreadyCached);
+}
+
+#[test]
+fn test_rejected_session_is_reported_and_nothing_cached() {
+    let (base_url, server) = serve(vec![(400, "Puzzle inputs differ by user.")]);
+    let path = crate::fixtures::temp_dir("rejected_session").join("input_day_3.txt");
+
+    let error = cache_input(
+        || Ok(Client::new("expired").with_base_url(&base_url)),
+        3,
+        &path,
+    )
+    .unwrap_err();
+
+    assert!(matches!(error, ClientError::Status { status: 400, .. }));
+    assert!(error.to_string().contains("session token may have expired"));
+    assert!(!path.exists());
+    server.join().unwrap();
+}
+
+#[test]
+fn test_session_prefers_environment_over_file() {
+    let dir = crate::fixtures::temp_dir("session");
+    let session_file = dir.join(".aoc_session");
+
+    assert!(matches!(
+        session_from(None, &session_file),
+        Err(ClientError::NoSession(_))
+    ));
+
+    std::fs::write(&session_file, "from-file\n").unwrap();
+    assert_eq!(session_from(None, &session_file).unwrap(), "from-file");
+    assert_eq!(
+        session_from(Some("from-env".to_string()), &session_file).unwrap(),
+        "from-env"
+    );
+}
+
+#[test]
+fn test_submit_posts_level_and_answer() {
+    let (base_url, server) = serve(vec![(
+        200,
+        "<main><article><p>That's the right answer!  You are <em>one gold star</em> closer.</p></article></main>",
+    )]);
+    let client = Client::new("abc123").with_base_url(&base_url);
+
+    assert_eq!(
+        client.submit(16, Part::Two, "1707").unwrap(),
+        Verdict::Correct
+    );
+
+    let received = server.join().unwrap();
+    assert_eq!(received[0].method, "POST");
+    assert_eq!(received[0].url, "/2022/day/16/answer");
+    assert_eq!(received[0].cookie.as_deref(), Some("session=abc123"));
+    assert_eq!(received[0].body, "level=2&answer=1707");
+}
+
+#[test]
+fn test_verdicts_from_responses() {
+    let verdict =
+        |message: &str| Verdict::from_page(&format!("<article><p>{}</p></article>", message));
+
+    assert_eq!(
+        verdict("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2022/day/16\">[Return to Day 16]</a>"),
+        Verdict::Incorrect(Rejection::TooHigh)
+    );
+    assert_eq!(
+        verdict("That's not the right answer; your answer is too low."),
+        Verdict::Incorrect(Rejection::TooLow)
+    );
+    assert_eq!(
+        verdict("That's not the right answer.  If you're stuck, there are some general tips."),
+        Verdict::Incorrect(Rejection::Wrong)
+    );
+    assert_eq!(
+        verdict("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 39s left to wait."),
+        Verdict::Wait(Duration::from_secs(279))
+    );
+    assert_eq!(
+        verdict("You gave an answer too recently.  You have 39s left to wait."),
+        Verdict::Wait(Duration::from_secs(39))
+    );
+    assert_eq!(
+        verdict("You don't seem to be solving the right level.  Did you already complete it?"),
+        Verdict::AlreadySolved
+    );
+    assert_eq!(
+        verdict("Something <em>new</em>\n  happened."),
+        Verdict::Unrecognised("Something new happened.".to_string())
+    );
+}
@@ -0,0 +1,127 @@
+use crate::error::Error;
+use crate::progress;
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A simulation that eventually repeats: after `start` steps the state comes back round to the
+/// same point every `length` steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<M> {
+    pub start: u64,
+    pub length: u64,
+    /// The metric after each step, from the initial state up to the end of the first cycle.
+    metrics: Vec<M>,
+}
+
+/// Steps `state` until `key` gives the same key for two different steps, keeping track of
+/// `metric` along the way so that it can be extrapolated with [`Cycle::extrapolate`].
+///
+/// The key only needs to capture what decides the future of the simulation, e.g. the top of a
+/// tower rather than the whole thing. It is compared against every earlier key, so if the
+/// simulation doesn't repeat this only stops when the [`progress::Budget`] it runs under does.
+pub fn find<S, K, M>(
+    mut state: S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut metric: impl FnMut(&S) -> M,
+) -> Result<Cycle<M>, Error>
+where
+    K: Eq + Hash,
+{
+    let mut seen: HashMap<K, u64> = HashMap::new();
+    let mut metrics = vec![];
+    for steps in 0.. {
+        progress::update(steps, None)?;
+        metrics.push(metric(&state));
+        if let Some(start) = seen.insert(key(&state), steps) {
+            return Ok(Cycle {
+                start,
+                length: steps - start,
+                metrics,
+            });
+        }
+        step(&mut state);
+    }
+    unreachable!("the step count can't run out")
+}
+
+impl<M> Cycle<M> {
+    /// The step before the end of the first cycle that is in the same state as `steps`.
+    pub fn equivalent_step(&self, steps: u64) -> u64 {
+        if steps <= self.start + self.length {
+            steps
+        } else {
+            self.start + (steps - self.start) % self.length
+        }
+    }
+
+    /// The metric after `steps` steps, assuming it changes by the same amount every cycle. `None`
+    /// if the number of whole cycles or the metric itself doesn't fit in `M`.
+    pub fn extrapolate(&self, steps: u64) -> Option<M>
+    where
+        M: Copy + CheckedAdd + CheckedSub + CheckedMul + TryFrom<u64>,
+    {
+        let equivalent = self.equivalent_step(steps);
+        let metric = self.metrics[equivalent as usize];
+        if equivalent == steps {
+            return Some(metric);
+        }
+        let per_cycle = self.metrics[(self.start + self.length) as usize]
+            .checked_sub(&self.metrics[self.start as usize])?;
+        let cycles = M::try_from((steps - self.start) / self.length).ok()?;
+        metric.checked_add(&per_cycle.checked_mul(&cycles)?)
+    }
+}
+
+#[test]
+fn test_find_cycle() {
+    // Doubling mod 100 goes 1, 2 and then round the same 20 numbers from 4 to 52 forever
+    let cycle = find(1u64, |n| *n = *n * 2 % 100, |n| *n, |n| *n).unwrap();
+
+    assert_eq!((cycle.start, cycle.length), (2, 20));
+    assert_eq!(cycle.equivalent_step(10), 10);
+    assert_eq!(cycle.equivalent_step(22), 22);
+    assert_eq!(cycle.equivalent_step(23), 3);
+    assert_eq!(cycle.equivalent_step(2 + 20 * 1000 + 5), 7);
+}
+
+#[test]
+fn test_extrapolate() {
+    // A counter that goes up by the state each step, where the state goes 0, 1, 2, 0, 1, 2, ...
+    let cycle = find(
+        (0u64, 0u64),
+        |(n, total)| {
+            *total += *n;
+            *n = (*n + 1) % 3;
+        },
+        |(n, _)| *n,
+        |(_, total)| *total,
+    )
+    .unwrap();
+
+    assert_eq!((cycle.start, cycle.length), (0, 3));
+    assert_eq!(cycle.extrapolate(2), Some(1));
+    assert_eq!(cycle.extrapolate(3), Some(3));
+    assert_eq!(cycle.extrapolate(3_000_000_001), Some(3_000_000_000));
+    assert_eq!(
+        find(0u8, |n| *n = (*n + 1) % 2, |n| *n, |n| *n)
+            .unwrap()
+            .extrapolate(1_000),
+        None
+    );
+
+    // The number of cycles fits, but the total they add up to doesn't
+    let cycle = find(
+        (false, 0u64),
+        |(odd, total)| {
+            *total += 10;
+            *odd = !*odd;
+        },
+        |(odd, _)| *odd,
+        |(_, total)| *total,
+    )
+    .unwrap();
+    assert_eq!(cycle.extrapolate(1_000), Some(10_000));
+    assert_eq!(cycle.extrapolate(u64::MAX), None);
+}
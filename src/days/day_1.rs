@@ -0,0 +1,81 @@
+use crate::error::{parse_number, Error, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::input::{blocks, lines};
+use crate::solution::{Answer, Solution};
+
+/// The snacks one elf is carrying.
+#[derive(Debug)]
+pub struct Elf {
+    pub snack_calories: Vec<i32>,
+    pub total_calories: i32,
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Parsed = Vec<Elf>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Elf>, Error> {
+        Ok(parse_elves(input)?)
+    }
+
+    fn part_one(&self, all_elves: &Vec<Elf>) -> Result<Answer, Error> {
+        Ok(part_one(all_elves).into())
+    }
+
+    fn part_two(&self, all_elves: &Vec<Elf>) -> Result<Answer, Error> {
+        Ok(part_two(all_elves).into())
+    }
+}
+
+/// `size` elves (at least three), each carrying a handful of snacks.
+impl Generate for Day1 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (0..size.max(3))
+            .map(|_| {
+                (0..rng.range(1..=15))
+                    .map(|_| rng.range(1000..=60000).to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+/// Reads each elf's snacks, one number per line with a blank line between elves.
+pub fn parse_elves(input_text: &str) -> Result<Vec<Elf>, ParseError> {
+    blocks(input_text)
+        .into_iter()
+        .map(|elf_text| {
+            let snack_calories = lines(elf_text)
+                .map(|line| parse_number(input_text, line.trim(), "a number of calories"))
+                .collect::<Result<Vec<i32>, _>>()?;
+            let total_calories = snack_calories.iter().sum();
+            Ok(Elf {
+                snack_calories,
+                total_calories,
+            })
+        })
+        .collect()
+}
+
+/// The most calories any one elf is carrying.
+pub fn part_one(all_elves: &[Elf]) -> i32 {
+    let mut largest_calories = 0;
+    for elf in all_elves {
+        if largest_calories < elf.total_calories {
+            largest_calories = elf.total_calories;
+        }
+    }
+    largest_calories
+}
+
+/// The calories carried by the three elves carrying the most, added together.
+pub fn part_two(all_elves: &[Elf]) -> i32 {
+    let mut totals: Vec<i32> = all_elves.iter().map(|elf| elf.total_calories).collect();
+    totals.sort_by(|a, b| b.cmp(a));
+
+    totals.iter().take(3).sum()
+}
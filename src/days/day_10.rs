@@ -0,0 +1,115 @@
+use crate::error::{parse_number, Error, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::grid::Grid;
+use crate::input::lines;
+use crate::solution::{Answer, Solution};
+
+/// A `noop` or `addx`, as how many cycles it takes and what it then adds to the X register.
+#[derive(Debug)]
+pub struct Instruction {
+    pub wait_time: u32,
+    pub add_amount: i32,
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Parsed = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>, Error> {
+        Ok(parse_instructions(input)?)
+    }
+
+    fn part_one(&self, instructions: &Vec<Instruction>) -> Result<Answer, Error> {
+        Ok(part_one(instructions).into())
+    }
+
+    fn part_two(&self, instructions: &Vec<Instruction>) -> Result<Answer, Error> {
+        Ok(part_two(instructions).into())
+    }
+}
+
+/// `size` instructions.
+impl Generate for Day10 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                if rng.chance(1, 3) {
+                    "noop".to_string()
+                } else {
+                    format!("addx {}", rng.range(-15..=15))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Reads the program, one instruction per line.
+pub fn parse_instructions(input_text: &str) -> Result<Vec<Instruction>, ParseError> {
+    lines(input_text)
+        .map(|line| parse_instruction(input_text, line))
+        .collect()
+}
+
+fn parse_instruction(input_text: &str, line: &str) -> Result<Instruction, ParseError> {
+    if line == "noop" {
+        return Ok(Instruction {
+            wait_time: 1,
+            add_amount: 0,
+        });
+    }
+    match line.strip_prefix("addx ") {
+        Some(amount) => Ok(Instruction {
+            wait_time: 2,
+            add_amount: parse_number(input_text, amount, "a number to add")?,
+        }),
+        None => Err(ParseError::at(input_text, line, "`noop` or `addx`")),
+    }
+}
+
+/// The signal strengths during the 20th, 60th, 100th, 140th, 180th and 220th cycles, added
+/// together.
+pub fn part_one(instructions: &[Instruction]) -> i32 {
+    let mut clock_cycle = 0;
+    let mut register_x: i32 = 1;
+    let mut total = 0;
+    for instruction in instructions {
+        for _ in 0..instruction.wait_time {
+            clock_cycle += 1;
+
+            if clock_cycle >= 20 && (clock_cycle - 20) % 40 == 0 && clock_cycle <= 220 {
+                total += clock_cycle * register_x;
+            }
+        }
+        register_x += instruction.add_amount;
+    }
+    total
+}
+
+/// The CRT's screen is 40 pixels wide and 6 tall, drawn one pixel per cycle row by row.
+const CRT_WIDTH: usize = 40;
+const CRT_HEIGHT: usize = 6;
+
+/// What the program draws on the CRT, one line of text per row.
+pub fn part_two(instructions: &[Instruction]) -> String {
+    let mut clock_cycle = 0;
+    let mut register_x: i32 = 1;
+    let mut screen = Grid::new(CRT_WIDTH, CRT_HEIGHT, '.');
+    for instruction in instructions {
+        for _ in 0..instruction.wait_time {
+            let render_pos = (clock_cycle % CRT_WIDTH, clock_cycle / CRT_WIDTH);
+            clock_cycle += 1;
+
+            // If the current render position is within 1 of the register_x (accounts for width of 3)
+            if i32::abs(register_x - render_pos.0 as i32) <= 1 {
+                if let Some(pixel) = screen.get_mut(render_pos) {
+                    *pixel = '#';
+                }
+            }
+        }
+        register_x += instruction.add_amount;
+    }
+    screen.to_string()
+}
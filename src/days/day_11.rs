@@ -0,0 +1,364 @@
+use crate::error::{parse_number, Error, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::input::{blocks, lines};
+use crate::params::{self, Param};
+use crate::solution::{Answer, Solution};
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Add(Option<u64>),
+    Multiply(Option<u64>),
+}
+
+#[derive(Debug, Clone)]
+struct Test {
+    num: u64,
+    if_divisible_throw_to: u32,
+    if_not_divisible_throw_to: u32,
+}
+
+/// A monkey holding items, with how it changes their worry levels and who it throws them to.
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: VecDeque<u64>,
+    operation: Operation,
+    test: Test,
+    num_inspections: u64,
+}
+
+impl Monkey {
+    /// The worry levels of the items the monkey is holding, in the order it will inspect them.
+    pub fn items(&self) -> &VecDeque<u64> {
+        &self.items
+    }
+
+    fn inspect(
+        &mut self,
+        part_two_remainder_theorem: Option<u64>,
+    ) -> Result<Option<(u32, u64)>, Error> {
+        let Some(worry_level) = self.items.pop_front() else {
+            return Ok(None);
+        };
+
+        let mut worry_level = match self.operation {
+            Operation::Add(num) => worry_level.checked_add(num.unwrap_or(worry_level)),
+            Operation::Multiply(num) => worry_level.checked_mul(num.unwrap_or(worry_level)),
+        }
+        .ok_or_else(|| {
+            Error::NoAnswer("the worry levels get too large to keep track of".to_string())
+        })?;
+        if let Some(remainder_theorem) = part_two_remainder_theorem {
+            // This is the chinese remainder theorem. It states that if the self.test.num are all coprime (no common multiples
+            // other than 1) then the remainder of the worry level with all of them multiplied together is going to be the same as the
+            // the remainder of the worry level with just one of the self.test.num. This means that we can take the mod of the worry worry_level
+            // with this new multiplied number and not affect the if else later on. So who the monkey throws to will not change due to this mod
+            worry_level %= remainder_theorem;
+        } else {
+            worry_level /= 3;
+        }
+        self.num_inspections += 1;
+        if worry_level.is_multiple_of(self.test.num) {
+            Ok(Some((self.test.if_divisible_throw_to, worry_level)))
+        } else {
+            Ok(Some((self.test.if_not_divisible_throw_to, worry_level)))
+        }
+    }
+}
+
+pub struct Day11 {
+    pub part_one_rounds: u32,
+    pub part_two_rounds: u32,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Day11 {
+            part_one_rounds: 20,
+            part_two_rounds: 10000,
+        }
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Parsed = Vec<Monkey>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Monkey>, Error> {
+        Ok(parse_monkeys(input)?)
+    }
+
+    fn part_one(&self, monkeys: &Vec<Monkey>) -> Result<Answer, Error> {
+        Ok(part_one(monkeys, self.part_one_rounds)?.into())
+    }
+
+    fn part_two(&self, monkeys: &Vec<Monkey>) -> Result<Answer, Error> {
+        Ok(part_two(monkeys, self.part_two_rounds)?.into())
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new(
+                "part_one_rounds",
+                "How many rounds the monkeys play in part one",
+                self.part_one_rounds.into(),
+            ),
+            Param::new(
+                "part_two_rounds",
+                "How many rounds the monkeys play in part two",
+                self.part_two_rounds.into(),
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: i64) -> Result<(), String> {
+        match name {
+            "part_one_rounds" => self.part_one_rounds = params::non_negative(value)?,
+            "part_two_rounds" => self.part_two_rounds = params::non_negative(value)?,
+            _ => return Err(format!("there is no parameter called `{}`", name)),
+        }
+        Ok(())
+    }
+}
+
+/// Four to eight monkeys holding `size` items between them. Like the real puzzles each monkey
+/// tests for a different prime and exactly one of them squares the worry level. Some monkeys pass
+/// an item round to the squaring one so often in part one that its worry level gets too large,
+/// so those are thrown away and tried again.
+impl Generate for Day11 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        loop {
+            let input_text = generate_monkeys(rng, size);
+            let monkeys = parse_monkeys(&input_text).expect("generated monkeys are valid");
+            if part_one(&monkeys, self.part_one_rounds).is_ok() {
+                return input_text;
+            }
+        }
+    }
+}
+
+/// One attempt at [`Day11`]'s generated input.
+fn generate_monkeys(rng: &mut Rng, size: usize) -> String {
+    let num_monkeys = rng.range(4..=8) as usize;
+    let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut primes);
+    let mut items = vec![vec![]; num_monkeys];
+    for _ in 0..size {
+        items[rng.index(num_monkeys)].push(rng.range(50..=99).to_string());
+    }
+    let squarer = rng.index(num_monkeys);
+
+    (0..num_monkeys)
+        .map(|id| {
+            let operation = if id == squarer {
+                "* old".to_string()
+            } else if rng.chance(1, 2) {
+                format!("+ {}", rng.range(1..=8))
+            } else {
+                format!("* {}", rng.range(2..=19))
+            };
+            let mut other_monkey = || (id + 1 + rng.index(num_monkeys - 1)) % num_monkeys;
+            let (if_true, if_false) = (other_monkey(), other_monkey());
+            [
+                format!("Monkey {}:", id),
+                format!("  Starting items: {}", items[id].join(", ")),
+                format!("  Operation: new = old {}", operation),
+                format!("  Test: divisible by {}", primes[id]),
+                format!("    If true: throw to monkey {}", if_true),
+                format!("    If false: throw to monkey {}", if_false),
+            ]
+            .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Reads every monkey's notes, separated by blank lines.
+pub fn parse_monkeys(input_text: &str) -> Result<Vec<Monkey>, ParseError> {
+    let monkey_strings = blocks(input_text);
+    if monkey_strings.len() < 2 {
+        return Err(ParseError::at(
+            input_text,
+            &input_text[input_text.len()..],
+            "at least two monkeys",
+        ));
+    }
+
+    monkey_strings
+        .iter()
+        .enumerate()
+        .map(|(index, monkey_string)| {
+            parse_monkey(input_text, monkey_string, index, monkey_strings.len())
+        })
+        .collect()
+}
+
+/// Parses one monkey's block. Monkeys are looked up by their position in the list, so the id
+/// must match it and every monkey thrown to has to exist.
+fn parse_monkey(
+    input_text: &str,
+    monkey_string: &str,
+    index: usize,
+    num_monkeys: usize,
+) -> Result<Monkey, ParseError> {
+    let mut monkey_lines = lines(monkey_string);
+    let mut next_field = |prefix: &str| {
+        let line = monkey_lines
+            .next()
+            .unwrap_or(&monkey_string[monkey_string.len()..]);
+        line.trim_start()
+            .strip_prefix(prefix)
+            .ok_or_else(|| ParseError::at(input_text, line, format!("`{}`", prefix.trim_end())))
+    };
+    let monkey_id = |target: &str, expected: &str| {
+        let id = parse_number::<u32>(input_text, target, expected)?;
+        if id as usize >= num_monkeys {
+            return Err(ParseError::at(
+                input_text,
+                target,
+                format!("a monkey from 0 to {}", num_monkeys - 1),
+            ));
+        }
+        Ok(id)
+    };
+
+    let id_text = next_field("Monkey ")?;
+    let id_text = id_text
+        .strip_suffix(':')
+        .ok_or_else(|| ParseError::at(input_text, &id_text[id_text.len()..], "`:`"))?;
+    let id = parse_number::<usize>(input_text, id_text, "a monkey id")?;
+    if id != index {
+        return Err(ParseError::at(
+            input_text,
+            id_text,
+            format!("monkey {} (monkeys must be listed in order)", index),
+        ));
+    }
+
+    let items = next_field("Starting items:")?
+        .split(',')
+        .map(str::trim)
+        .filter(|num| !num.is_empty())
+        .map(|num| parse_number::<u64>(input_text, num, "a worry level"))
+        .collect::<Result<VecDeque<u64>, _>>()?;
+
+    let op = next_field("Operation: new = old ")?;
+    let (operator, num) = op
+        .split_once(' ')
+        .ok_or_else(|| ParseError::at(input_text, op, "an operator and a number or `old`"))?;
+    let num = match num {
+        "old" => None,
+        _ => Some(parse_number::<u64>(input_text, num, "a number or `old`")?),
+    };
+    let operation = match operator {
+        "*" => Operation::Multiply(num),
+        "+" => Operation::Add(num),
+        _ => return Err(ParseError::at(input_text, operator, "`*` or `+`")),
+    };
+
+    let divisor = next_field("Test: divisible by ")?;
+    let test_num = parse_number::<u64>(input_text, divisor, "a number to divide by")?;
+    if test_num == 0 {
+        return Err(ParseError::at(
+            input_text,
+            divisor,
+            "a number greater than 0",
+        ));
+    }
+
+    let if_divisible_throw_to = monkey_id(next_field("If true: throw to monkey ")?, "a monkey id")?;
+    let if_not_divisible_throw_to =
+        monkey_id(next_field("If false: throw to monkey ")?, "a monkey id")?;
+
+    Ok(Monkey {
+        items,
+        operation,
+        test: Test {
+            num: test_num,
+            if_divisible_throw_to,
+            if_not_divisible_throw_to,
+        },
+        num_inspections: 0,
+    })
+}
+
+/// Plays `num_rounds` rounds with the monkeys, whose worry levels are divided by 3 after each
+/// inspection, and multiplies together the inspections of the two busiest monkeys. There have
+/// to be at least two monkeys.
+pub fn part_one(monkeys: &[Monkey], num_rounds: u32) -> Result<u64, Error> {
+    monkey_business(monkeys, num_rounds, None)
+}
+
+/// Like [`part_one`] but without the worry levels being divided by 3.
+pub fn part_two(monkeys: &[Monkey], num_rounds: u32) -> Result<u64, Error> {
+    let remainder_theorem = monkeys
+        .iter()
+        .try_fold(1u64, |product, monkey| product.checked_mul(monkey.test.num))
+        .ok_or_else(|| Error::NoAnswer("the monkeys' tests multiply to too much".to_string()))?;
+    monkey_business(monkeys, num_rounds, Some(remainder_theorem))
+}
+
+/// Plays `num_rounds` rounds with a copy of `monkeys` and multiplies together the inspections
+/// of the two busiest monkeys.
+fn monkey_business(
+    monkeys: &[Monkey],
+    num_rounds: u32,
+    part_two_remainder_theorem: Option<u64>,
+) -> Result<u64, Error> {
+    if monkeys.len() < 2 {
+        return Err(Error::NoAnswer(
+            "there have to be at least two monkeys".to_string(),
+        ));
+    }
+    let mut monkeys = monkeys.to_vec();
+    for _ in 0..num_rounds {
+        for i in 0..monkeys.len() {
+            for _ in 0..monkeys[i].items.len() {
+                let returned_option = monkeys[i].inspect(part_two_remainder_theorem)?;
+                if returned_option.is_none() {
+                    break;
+                }
+
+                let (id, worry_level) = returned_option.unwrap();
+                monkeys
+                    .get_mut(id as usize)
+                    .ok_or_else(|| {
+                        Error::NoAnswer(format!("there is no monkey {} to throw to", id))
+                    })?
+                    .items
+                    .push_back(worry_level);
+            }
+        }
+    }
+    monkeys.sort_by_key(|monkey| std::cmp::Reverse(monkey.num_inspections));
+    monkeys[0]
+        .num_inspections
+        .checked_mul(monkeys[1].num_inspections)
+        .ok_or_else(|| Error::NoAnswer("the monkeys inspect too many items".to_string()))
+}
+
+#[test]
+fn test_monkeys_are_left_alone() {
+    let input_text = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 54
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+    let monkeys = parse_monkeys(input_text).unwrap();
+
+    let monkey_business = part_one(&monkeys, 20).unwrap();
+    assert_eq!(part_one(&monkeys, 20).unwrap(), monkey_business);
+    assert_eq!(monkeys[0].items(), &[79, 98]);
+    assert_eq!(
+        part_one(&monkeys[..1], 20).unwrap_err().to_string(),
+        "no answer: there have to be at least two monkeys"
+    );
+}
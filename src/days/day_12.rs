@@ -0,0 +1,250 @@
+use crate::error::{Error, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::grid::Grid;
+use crate::search;
+use crate::solution::{Answer, Part, Solution};
+use crate::visualize::{Frame, Recorder, Visualize};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+/// The heights as characters (`S` and `E` already replaced by `a` and `z`) with the start and
+/// end as `(x, y)`.
+#[derive(Debug, Clone)]
+pub struct Heightmap {
+    pub heights: Grid<u32>,
+    pub start_pos: (usize, usize),
+    pub end_pos: (usize, usize),
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Parsed = Heightmap;
+
+    fn parse(&self, input: &str) -> Result<Heightmap, Error> {
+        Ok(parse_heightmap(input)?)
+    }
+
+    fn part_one(&self, heightmap: &Heightmap) -> Result<Answer, Error> {
+        Ok(part_one(heightmap)?.into())
+    }
+
+    fn part_two(&self, heightmap: &Heightmap) -> Result<Answer, Error> {
+        Ok(part_two(heightmap)?.into())
+    }
+}
+
+/// A hill `size` squares wide (at least 26) and a quarter as tall. One row climbs steadily from
+/// `S` on the left to `E` on the right, so the end can always be reached.
+impl Generate for Day12 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let width = size.max(26);
+        let height = (size / 4).max(3);
+        let path_row = rng.index(height);
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| {
+                        let slope = (x * 25 / (width - 1)) as i64;
+                        match (x, y) {
+                            (0, y) if y == path_row => 'S',
+                            (x, y) if y == path_row && x == width - 1 => 'E',
+                            (_, y) if y == path_row => (b'a' + slope as u8) as char,
+                            _ => (b'a' + (slope + rng.range(-4..=2)).clamp(0, 25) as u8) as char,
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// One frame per step of a breadth first search, showing the squares reached so far as `~` and
+/// the ones just reached as `@`. Part two searches from every `a` at once, which finds the same
+/// shortest path as searching from each of them in turn. The last frame shows the path as `*`.
+impl Visualize for Day12 {
+    fn visualize(
+        &self,
+        heightmap: &Heightmap,
+        part: Part,
+        recorder: &mut Recorder,
+    ) -> Result<(), Error> {
+        let mut frontier: Vec<(usize, usize)> = match part {
+            Part::One => vec![heightmap.start_pos],
+            Part::Two => lowest_points(heightmap),
+        };
+        let mut parents: HashMap<(usize, usize), Option<(usize, usize)>> =
+            frontier.iter().map(|pos| (*pos, None)).collect();
+
+        let mut steps = 0;
+        while !frontier.is_empty() && !parents.contains_key(&heightmap.end_pos) {
+            let draw = || {
+                draw_search(heightmap, &parents, &frontier, &[]).with_caption(format!(
+                    "{} steps, {} squares reached",
+                    steps,
+                    parents.len()
+                ))
+            };
+            if !recorder.step(draw) {
+                return Ok(());
+            }
+
+            let mut next_frontier = vec![];
+            for (x, y) in frontier {
+                for neighbour in climbable(&heightmap.heights, (x, y)) {
+                    if let Entry::Vacant(entry) = parents.entry(neighbour) {
+                        entry.insert(Some((x, y)));
+                        next_frontier.push(neighbour);
+                    }
+                }
+            }
+            frontier = next_frontier;
+            steps += 1;
+        }
+
+        let mut path = vec![];
+        let mut pos = parents.get(&heightmap.end_pos).map(|_| heightmap.end_pos);
+        while let Some(current) = pos {
+            path.push(current);
+            pos = parents[&current];
+        }
+        let caption = if path.is_empty() {
+            "the end can't be reached".to_string()
+        } else {
+            format!("the shortest path takes {} steps", path.len() - 1)
+        };
+        recorder.last(|| draw_search(heightmap, &parents, &frontier, &path).with_caption(caption));
+        Ok(())
+    }
+}
+
+fn draw_search(
+    heightmap: &Heightmap,
+    reached: &HashMap<(usize, usize), Option<(usize, usize)>>,
+    frontier: &[(usize, usize)],
+    path: &[(usize, usize)],
+) -> Frame {
+    let mut frame = Frame::new(heightmap.heights.width(), heightmap.heights.height(), '.');
+    for ((x, y), height) in heightmap.heights.iter() {
+        frame.set(x, y, char::from_u32(*height).unwrap_or('?'));
+    }
+    for (x, y) in reached.keys() {
+        frame.set(*x, *y, '~');
+    }
+    for (x, y) in frontier.iter().chain(path) {
+        frame.set(*x, *y, if path.is_empty() { '@' } else { '*' });
+    }
+    frame.set(heightmap.start_pos.0, heightmap.start_pos.1, 'S');
+    frame.set(heightmap.end_pos.0, heightmap.end_pos.1, 'E');
+    frame
+}
+
+/// Reads the grid of heights and finds the start and end.
+pub fn parse_heightmap(input: &str) -> Result<Heightmap, ParseError> {
+    let mut start_pos = None;
+    let mut end_pos = None;
+
+    let heights = Grid::parse(input, |x, y, c| {
+        let marker = match c {
+            'S' => &mut start_pos,
+            'E' => &mut end_pos,
+            'a'..='z' => return Ok(c as u32),
+            _ => return Err("a height from a-z".to_string()),
+        };
+        if marker.is_some() {
+            return Err(format!("only one `{}`", c));
+        }
+        *marker = Some((x, y));
+        Ok(if c == 'S' { 'a' } else { 'z' } as u32)
+    })?;
+
+    let end_of_input = &input[input.len()..];
+    Ok(Heightmap {
+        heights,
+        start_pos: start_pos.ok_or_else(|| ParseError::at(input, end_of_input, "a start `S`"))?,
+        end_pos: end_pos.ok_or_else(|| ParseError::at(input, end_of_input, "an end `E`"))?,
+    })
+}
+
+/// The fewest steps from the start to the end.
+pub fn part_one(heightmap: &Heightmap) -> Result<u64, Error> {
+    shortest_climb(heightmap, [heightmap.start_pos])
+        .ok_or_else(|| Error::NoAnswer("the end can't be reached from the start".to_string()))
+}
+
+/// The fewest steps to the end from any square at height `a`. Searching from every `a` at once
+/// finds the shortest of the paths from each of them.
+pub fn part_two(heightmap: &Heightmap) -> Result<u64, Error> {
+    shortest_climb(heightmap, lowest_points(heightmap))
+        .ok_or_else(|| Error::NoAnswer("the end can't be reached from any `a`".to_string()))
+}
+
+fn shortest_climb(
+    heightmap: &Heightmap,
+    starts: impl IntoIterator<Item = (usize, usize)>,
+) -> Option<u64> {
+    search::bfs(
+        starts,
+        |pos| climbable(&heightmap.heights, *pos),
+        |pos| *pos == heightmap.end_pos,
+    )
+    .goal_cost()
+}
+
+/// The squares next to `pos` that are at most one higher, since climbing gear isn't allowed.
+fn climbable(
+    heights: &Grid<u32>,
+    pos: (usize, usize),
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    heights
+        .neighbours(pos)
+        .filter(move |neighbour| heights[*neighbour] <= heights[pos] + 1)
+}
+
+/// Every square at height `a`, which part two can start from.
+fn lowest_points(heightmap: &Heightmap) -> Vec<(usize, usize)> {
+    heightmap
+        .heights
+        .iter()
+        .filter(|(_, height)| **height == 'a' as u32)
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+#[test]
+fn part_one_test() {
+    let input_text = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi";
+
+    let num_steps = part_one(&parse_heightmap(input_text).unwrap()).unwrap();
+    assert_eq!(num_steps, 31);
+}
+#[test]
+fn part_two_test() {
+    let input_text = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi";
+
+    let num_steps = part_two(&parse_heightmap(input_text).unwrap()).unwrap();
+    assert_eq!(num_steps, 29);
+}
+
+#[test]
+fn test_visualize_part_one() {
+    let input_text = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi";
+    let mut frames: Vec<Frame> = vec![];
+    let mut recorder = Recorder::new(&mut frames, 1, None);
+
+    Day12
+        .visualize(
+            &parse_heightmap(input_text).unwrap(),
+            Part::One,
+            &mut recorder,
+        )
+        .unwrap();
+
+    assert_eq!(recorder.finish().unwrap(), 32);
+    assert_eq!(frames[0].to_string(), input_text);
+    let last = frames.last().unwrap();
+    assert_eq!(last.caption, "the shortest path takes 31 steps");
+    assert_eq!(last.to_string().matches('*').count(), 30);
+}
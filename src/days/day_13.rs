@@ -0,0 +1,310 @@
+use crate::error::{parse_number, Error, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::input::{blocks, lines};
+use crate::solution::{Answer, Solution};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A packet, or one of the values inside it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataTypes {
+    Integer(u32),
+    List(Vec<DataTypes>),
+}
+
+impl fmt::Display for DataTypes {
+    /// Writes the value the way it appears in the input, e.g. `[1,[2,3],[]]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataTypes::Integer(num) => write!(f, "{}", num),
+            DataTypes::List(items) => {
+                write!(f, "[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Parsed = Vec<(DataTypes, DataTypes)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, Error> {
+        Ok(parse_packet_pairs(input)?)
+    }
+
+    fn part_one(&self, pairs: &Self::Parsed) -> Result<Answer, Error> {
+        Ok(part_one(pairs).into())
+    }
+
+    fn part_two(&self, pairs: &Self::Parsed) -> Result<Answer, Error> {
+        Ok(part_two(pairs).into())
+    }
+}
+
+/// `size` pairs of packets.
+impl Generate for Day13 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| format!("{}\n{}", random_packet(rng, 0), random_packet(rng, 0)))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+/// A list of up to five values, each of them a number or (less than four lists deep) a list.
+fn random_packet(rng: &mut Rng, depth: usize) -> DataTypes {
+    DataTypes::List(
+        (0..rng.range(0..=5))
+            .map(|_| {
+                if depth < 4 && rng.chance(1, 3) {
+                    random_packet(rng, depth + 1)
+                } else {
+                    DataTypes::Integer(rng.range(0..=10) as u32)
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Reads the pairs of packets, separated by blank lines.
+pub fn parse_packet_pairs(input_file: &str) -> Result<Vec<(DataTypes, DataTypes)>, ParseError> {
+    blocks(input_file)
+        .into_iter()
+        .map(|pair| {
+            let pair_lines = lines(pair).collect::<Vec<&str>>();
+            match pair_lines[..] {
+                [left, right] => Ok((
+                    parse_packet(input_file, left)?,
+                    parse_packet(input_file, right)?,
+                )),
+                [.., last] => Err(ParseError::at(
+                    input_file,
+                    if pair_lines.len() < 2 {
+                        &last[last.len()..]
+                    } else {
+                        pair_lines[2]
+                    },
+                    "exactly two packets in each pair",
+                )),
+                [] => unreachable!("blocks are never empty"),
+            }
+        })
+        .collect()
+}
+
+/// The indices (from 1) of the pairs that are already in the right order, added together.
+pub fn part_one(pairs: &[(DataTypes, DataTypes)]) -> u32 {
+    let mut sum = 0;
+    for (index, (left, right)) in pairs.iter().enumerate() {
+        let result = in_right_order(left, right);
+        let result = result.unwrap_or(true);
+        if result {
+            sum += index as u32 + 1;
+        }
+    }
+    sum
+}
+
+/// The decoder key: the positions of the two divider packets once every packet is sorted,
+/// multiplied together.
+pub fn part_two(pairs: &[(DataTypes, DataTypes)]) -> u32 {
+    let decoder_packet_2 = DataTypes::List(vec![DataTypes::List(vec![DataTypes::Integer(2)])]);
+    let decoder_packet_6 = DataTypes::List(vec![DataTypes::List(vec![DataTypes::Integer(6)])]);
+
+    let mut packets = pairs
+        .iter()
+        .flat_map(|(left, right)| [left.clone(), right.clone()])
+        .chain([decoder_packet_2.clone(), decoder_packet_6.clone()])
+        .collect::<Vec<DataTypes>>();
+
+    packets.sort_by(|a, b| match in_right_order(a, b) {
+        Some(result) => {
+            if result {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        }
+        None => Ordering::Equal,
+    });
+
+    let mut result = 1;
+    for (index, packet) in packets.iter().enumerate() {
+        if *packet == decoder_packet_2 || *packet == decoder_packet_6 {
+            result *= index + 1;
+        }
+    }
+    result as u32
+}
+
+/// Whether `left` comes before `right`, or `None` if the comparison can't tell them apart.
+pub fn in_right_order(left: &DataTypes, right: &DataTypes) -> Option<bool> {
+    let mut curr_bool = None;
+    match (&left, &right) {
+        (DataTypes::List(l_str), DataTypes::List(r_str)) => {
+            let mut i = 0;
+            while i < l_str.len() && i < r_str.len() && curr_bool.is_none() {
+                curr_bool = match (&l_str[i], &r_str[i]) {
+                    (DataTypes::Integer(l), DataTypes::Integer(r)) => {
+                        if l < r {
+                            return Some(true);
+                        } else if l > r {
+                            return Some(false);
+                        }
+                        None
+                    }
+                    (DataTypes::List(l), DataTypes::List(r)) => {
+                        in_right_order(&DataTypes::List(l.to_vec()), &DataTypes::List(r.to_vec()))
+                    }
+                    (DataTypes::List(l), DataTypes::Integer(r)) => in_right_order(
+                        &DataTypes::List(l.clone()),
+                        &DataTypes::List(vec![DataTypes::Integer(*r)]),
+                    ),
+                    (DataTypes::Integer(l), DataTypes::List(r)) => in_right_order(
+                        &DataTypes::List(vec![DataTypes::Integer(*l)]),
+                        &DataTypes::List(r.clone()),
+                    ),
+                };
+
+                i += 1;
+            }
+            if i == r_str.len() && i < l_str.len() && curr_bool.is_none() {
+                return Some(false);
+            } else if i == l_str.len() && i < r_str.len() && curr_bool.is_none() {
+                return Some(true);
+            }
+        }
+        (DataTypes::Integer(l), DataTypes::Integer(r)) => {
+            return match l.cmp(r) {
+                Ordering::Less => Some(true),
+                Ordering::Greater => Some(false),
+                Ordering::Equal => None,
+            };
+        }
+        (DataTypes::Integer(_), DataTypes::List(_)) => {
+            return in_right_order(&DataTypes::List(vec![left.clone()]), right);
+        }
+        (DataTypes::List(_), DataTypes::Integer(_)) => {
+            return in_right_order(left, &DataTypes::List(vec![right.clone()]));
+        }
+    }
+
+    curr_bool
+}
+
+/// Packets are compared recursively, so deeper nesting than any real input has is rejected
+/// rather than risking a stack overflow.
+const MAX_PACKET_DEPTH: usize = 100;
+
+/// Parses a packet such as `[1,[2,3],[]]`. Every packet is a list and must take up the whole line.
+fn parse_packet(input_file: &str, line: &str) -> Result<DataTypes, ParseError> {
+    if !line.starts_with('[') {
+        return Err(ParseError::at(input_file, line, "`[`"));
+    }
+    let (packet, rest) = parse_packet_value(input_file, line, 0)?;
+    if !rest.is_empty() {
+        return Err(ParseError::at(input_file, rest, "the end of the packet"));
+    }
+    Ok(packet)
+}
+
+fn parse_packet_value<'a>(
+    input_file: &str,
+    text: &'a str,
+    depth: usize,
+) -> Result<(DataTypes, &'a str), ParseError> {
+    if let Some(mut rest) = text.strip_prefix('[') {
+        if depth == MAX_PACKET_DEPTH {
+            return Err(ParseError::at(
+                input_file,
+                text,
+                format!("lists nested at most {} deep", MAX_PACKET_DEPTH),
+            ));
+        }
+        let mut current_list: Vec<DataTypes> = vec![];
+        if let Some(rest) = rest.strip_prefix(']') {
+            return Ok((DataTypes::List(current_list), rest));
+        }
+        loop {
+            let (value, after_value) = parse_packet_value(input_file, rest, depth + 1)?;
+            current_list.push(value);
+            if let Some(after_comma) = after_value.strip_prefix(',') {
+                rest = after_comma;
+            } else if let Some(after_list) = after_value.strip_prefix(']') {
+                return Ok((DataTypes::List(current_list), after_list));
+            } else {
+                return Err(ParseError::at(input_file, after_value, "`,` or `]`"));
+            }
+        }
+    }
+
+    let num_digits = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    if num_digits == 0 {
+        return Err(ParseError::at(input_file, text, "a number or `[`"));
+    }
+    let current_num = parse_number(input_file, &text[..num_digits], "a number")?;
+    Ok((DataTypes::Integer(current_num), &text[num_digits..]))
+}
+
+#[test]
+fn part_one_test() {
+    let input_text = std::fs::read_to_string("./inputs/tests/day_13/example.txt").unwrap();
+
+    assert_eq!(part_one(&parse_packet_pairs(&input_text).unwrap()), 13);
+}
+
+#[test]
+fn part_two_test() {
+    let input_text = std::fs::read_to_string("./inputs/tests/day_13/example.txt").unwrap();
+
+    assert_eq!(part_two(&parse_packet_pairs(&input_text).unwrap()), 140);
+}
+
+#[test]
+fn test_deeply_nested_packet_is_an_error() {
+    let line = "[".repeat(100_000);
+
+    assert_eq!(
+        parse_packet(&line, &line).unwrap_err().column,
+        MAX_PACKET_DEPTH + 1
+    );
+}
+
+/// Any packet, with lists nested a few levels deep.
+#[cfg(test)]
+fn packet_strategy() -> impl proptest::strategy::Strategy<Value = DataTypes> {
+    use proptest::prelude::*;
+
+    let value = any::<u32>()
+        .prop_map(DataTypes::Integer)
+        .prop_recursive(4, 64, 6, |inner| {
+            proptest::collection::vec(inner, 0..6).prop_map(DataTypes::List)
+        });
+    proptest::collection::vec(value, 0..6).prop_map(DataTypes::List)
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn printed_packets_parse_back(packet in packet_strategy()) {
+        let line = packet.to_string();
+
+        proptest::prop_assert_eq!(parse_packet(&line, &line).unwrap(), packet);
+    }
+
+    #[test]
+    fn parse_packet_pairs_never_panics(input in "[\\[\\],0-9\n]{0,64}|\\PC{0,64}") {
+        let _ = parse_packet_pairs(&input);
+    }
+}
@@ -0,0 +1,318 @@
+use crate::error::{parse_number, Error, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::input::lines;
+use crate::solution::{Answer, Part, Solution};
+use crate::visualize::{Frame, Recorder, Visualize};
+use std::collections::HashMap;
+#[derive(Debug, Clone)]
+enum Block {
+    Rock,
+    Sand,
+}
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Parsed = Vec<Vec<(i32, i32)>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, Error> {
+        Ok(get_rock_lines(input)?)
+    }
+
+    fn part_one(&self, rock_lines: &Self::Parsed) -> Result<Answer, Error> {
+        Ok(part_one(rock_lines).into())
+    }
+
+    fn part_two(&self, rock_lines: &Self::Parsed) -> Result<Answer, Error> {
+        Ok(part_two(rock_lines).into())
+    }
+}
+
+/// `size` paths of rock below the source of the sand.
+impl Generate for Day14 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let spread = 10 + size as i64 / 2;
+        (0..size.max(1))
+            .map(|_| {
+                let (mut x, mut y) = (500 + rng.range(-spread..=spread), rng.range(2..=spread));
+                let mut points = vec![format!("{},{}", x, y)];
+                let horizontal_first = rng.chance(1, 2);
+                for segment in 0..rng.range(1..=4) {
+                    let length = rng.range(1..=6) * if rng.chance(1, 2) { 1 } else { -1 };
+                    if (segment % 2 == 0) == horizontal_first {
+                        x += length;
+                    } else {
+                        y = (y + length).max(1);
+                    }
+                    points.push(format!("{},{}", x, y));
+                }
+                points.join(" -> ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// One frame per grain of sand that comes to rest.
+impl Visualize for Day14 {
+    fn visualize(
+        &self,
+        rock_lines: &Self::Parsed,
+        part: Part,
+        recorder: &mut Recorder,
+    ) -> Result<(), Error> {
+        let mut map = build_map(rock_lines);
+        let sand_emitter_pos: (i32, i32) = (500, 0);
+
+        let lowest_rock = map.keys().map(|key| key.1).max().unwrap();
+        // In part one sand can only come to rest between the outermost rocks, but in part two it
+        // spreads out as far as the floor is deep
+        let (left, right, bottom) = match part {
+            Part::One => (
+                map.keys().map(|key| key.0).min().unwrap(),
+                map.keys().map(|key| key.0).max().unwrap(),
+                lowest_rock,
+            ),
+            Part::Two => (
+                500 - lowest_rock - 2,
+                500 + lowest_rock + 2,
+                lowest_rock + 2,
+            ),
+        };
+        let draw = |map: &HashMap<(i32, i32), Block>, sand_units: i32| {
+            let mut frame = Frame::new((right - left + 1) as usize, (bottom + 1) as usize, '.');
+            frame.set((500 - left) as usize, 0, '+');
+            for ((x, y), block) in map {
+                let cell = match block {
+                    Block::Rock => '#',
+                    Block::Sand => 'o',
+                };
+                frame.set((x - left) as usize, *y as usize, cell);
+            }
+            if part == Part::Two {
+                for x in 0..frame.width() {
+                    frame.set(x, bottom as usize, '#');
+                }
+            }
+            frame.with_caption(format!("{} units of sand", sand_units))
+        };
+
+        let mut sand_units = 0;
+        loop {
+            let more_sand = match part {
+                Part::One => move_sand(&sand_emitter_pos, &mut map),
+                Part::Two => {
+                    sand_units += 1;
+                    move_sand_part_two(&sand_emitter_pos, &mut map, lowest_rock + 2)
+                }
+            };
+            if !more_sand {
+                break;
+            }
+            if part == Part::One {
+                sand_units += 1;
+            }
+            if !recorder.step(|| draw(&map, sand_units)) {
+                return Ok(());
+            }
+        }
+        recorder.last(|| draw(&map, sand_units));
+        Ok(())
+    }
+}
+
+/// How many units of sand come to rest before the rest start falling into the abyss.
+pub fn part_one(rock_lines: &[Vec<(i32, i32)>]) -> i32 {
+    let mut map = build_map(rock_lines);
+
+    let sand_emitter_pos: (i32, i32) = (500, 0);
+
+    let mut sand_units: i32 = 0;
+
+    while move_sand(&sand_emitter_pos, &mut map) {
+        sand_units += 1;
+    }
+    sand_units
+}
+
+/// How many units of sand come to rest on the floor two below the lowest rock before the source
+/// is blocked, counting the one that blocks it.
+pub fn part_two(rock_lines: &[Vec<(i32, i32)>]) -> i32 {
+    let mut map = build_map(rock_lines);
+
+    let sand_emitter_pos: (i32, i32) = (500, 0);
+
+    let mut sand_units: i32 = 1;
+
+    let cave_bottom_height = map.clone().into_keys().map(|key| key.1).max().unwrap() + 2;
+    while move_sand_part_two(&sand_emitter_pos, &mut map, cave_bottom_height) {
+        sand_units += 1;
+    }
+    sand_units
+}
+
+/// Reads the paths of rock as lists of `(x, y)` points. Each path's points have to be in a
+/// horizontal or vertical line with the point before.
+pub fn get_rock_lines(input_text: &str) -> Result<Vec<Vec<(i32, i32)>>, ParseError> {
+    let rock_lines = lines(input_text)
+        .map(|line| {
+            let mut rock_line: Vec<(i32, i32)> = vec![];
+            for coords in line.split(" -> ") {
+                let (x, y) = coords
+                    .split_once(',')
+                    .ok_or_else(|| ParseError::at(input_text, coords, "a point like `498,4`"))?;
+                let point = (
+                    parse_number(input_text, x, "an x coordinate")?,
+                    parse_number(input_text, y, "a y coordinate")?,
+                );
+
+                if let Some(previous) = rock_line.last() {
+                    if previous.0 != point.0 && previous.1 != point.1 {
+                        return Err(ParseError::at(
+                            input_text,
+                            coords,
+                            "a point in a straight line with the one before",
+                        ));
+                    }
+                }
+                rock_line.push(point);
+            }
+            Ok(rock_line)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if rock_lines.is_empty() {
+        return Err(ParseError::at(
+            input_text,
+            input_text,
+            "at least one path of rock",
+        ));
+    }
+    Ok(rock_lines)
+}
+
+fn build_map(rock_lines: &[Vec<(i32, i32)>]) -> HashMap<(i32, i32), Block> {
+    let mut map: HashMap<(i32, i32), Block> = HashMap::new();
+
+    for rock_line in rock_lines.iter() {
+        for i in 0..(rock_line.len() - 1) {
+            let (mut x1, mut y1) = rock_line[i];
+            let (x2, y2) = rock_line[i + 1];
+
+            let delta_x = i32::signum(x2 - x1);
+            let delta_y = i32::signum(y2 - y1);
+
+            while (x1 != x2) ^ (y1 != y2) {
+                map.insert((x1, y1), Block::Rock);
+
+                x1 += delta_x;
+                y1 += delta_y;
+            }
+        }
+        map.insert(rock_line[rock_line.len() - 1], Block::Rock); // Last in the array still needs to be added
+    }
+    map
+}
+
+fn move_sand(emitter_pos: &(i32, i32), map: &mut HashMap<(i32, i32), Block>) -> bool {
+    let mut current_pos = *emitter_pos;
+    let mut num_iterations = 0;
+    while num_iterations < 1000 {
+        let next_pos = (current_pos.0, current_pos.1 + 1);
+
+        if map.contains_key(&next_pos) {
+            if !map.contains_key(&(next_pos.0 - 1, next_pos.1)) {
+                return move_sand(&(next_pos.0 - 1, next_pos.1), map);
+            } else if !map.contains_key(&(next_pos.0 + 1, next_pos.1)) {
+                return move_sand(&(next_pos.0 + 1, next_pos.1), map);
+            } else if let std::collections::hash_map::Entry::Vacant(e) = map.entry(current_pos) {
+                e.insert(Block::Sand); // Has settled
+                return true;
+            }
+        }
+        current_pos = next_pos;
+        num_iterations += 1;
+    }
+    false
+}
+fn move_sand_part_two(
+    emitter_pos: &(i32, i32),
+    map: &mut HashMap<(i32, i32), Block>,
+    cave_bottom_height: i32,
+) -> bool {
+    let mut current_pos = *emitter_pos;
+    loop {
+        let next_pos = (current_pos.0, current_pos.1 + 1);
+
+        if map.contains_key(&next_pos) {
+            if !map.contains_key(&(next_pos.0 - 1, next_pos.1)) {
+                return move_sand_part_two(&(next_pos.0 - 1, next_pos.1), map, cave_bottom_height);
+            } else if !map.contains_key(&(next_pos.0 + 1, next_pos.1)) {
+                return move_sand_part_two(&(next_pos.0 + 1, next_pos.1), map, cave_bottom_height);
+            } else if let std::collections::hash_map::Entry::Vacant(e) = map.entry(current_pos) {
+                e.insert(Block::Sand); // Has settled
+
+                if current_pos.0 == 500 && current_pos.1 == 0 {
+                    return false;
+                }
+                return true;
+            }
+        }
+
+        if next_pos.1 == cave_bottom_height {
+            map.insert(current_pos, Block::Sand);
+            return true;
+        }
+        current_pos = next_pos;
+    }
+}
+#[test]
+fn part_one_test() {
+    let input_text = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    assert_eq!(24, part_one(&get_rock_lines(input_text).unwrap()));
+}
+
+#[test]
+fn part_two_test() {
+    let input_text = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    assert_eq!(93, part_two(&get_rock_lines(input_text).unwrap()));
+}
+
+#[test]
+fn test_visualize_part_one() {
+    let input_text = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+    let mut frames: Vec<Frame> = vec![];
+    let mut recorder = Recorder::new(&mut frames, 1, None);
+
+    Day14
+        .visualize(
+            &get_rock_lines(input_text).unwrap(),
+            Part::One,
+            &mut recorder,
+        )
+        .unwrap();
+
+    // The last grain falls into the abyss, so the final state is shown twice
+    assert_eq!(recorder.finish().unwrap(), 25);
+    let last = frames.last().unwrap();
+    assert_eq!(last.caption, "24 units of sand");
+    assert_eq!(
+        last.to_string(),
+        "\
+......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########."
+    );
+}
@@ -0,0 +1,472 @@
+use crate::error::{Error, NomResult, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::input::lines;
+use crate::params::{self, Param};
+use crate::point::Point2;
+use crate::progress;
+use crate::solution::{Answer, Solution};
+use std::ops::RangeInclusive;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::digit1,
+    combinator::{all_consuming, map_res, opt, recognize},
+    error::context,
+    sequence::preceded,
+};
+
+/// A sensor and the beacon closest to it, which means there are no other beacons within the same
+/// distance.
+#[derive(Debug, Clone)]
+pub struct Sensor {
+    pos: Point2,
+    closest_beacon: Point2,
+    manhattan_distance: i64,
+}
+
+impl Sensor {
+    pub fn new(sensor_pos: Point2, beacon_pos: Point2) -> Self {
+        let manhattan_distance = sensor_pos.manhattan(beacon_pos);
+
+        Sensor {
+            pos: sensor_pos,
+            closest_beacon: beacon_pos,
+            manhattan_distance,
+        }
+    }
+
+    pub fn pos(&self) -> Point2 {
+        self.pos
+    }
+
+    pub fn closest_beacon(&self) -> Point2 {
+        self.closest_beacon
+    }
+
+    /// The x coordinates of the positions on row `target_y_row` that are at least as close to
+    /// the sensor as its beacon, if there are any.
+    pub fn coverage_on_row(&self, target_y_row: i64) -> Option<RangeInclusive<i64>> {
+        let y_dist = target_y_row.checked_sub(self.pos.y)?.checked_abs()?;
+        let max_x_dist = self.manhattan_distance.checked_sub(y_dist)?;
+        if max_x_dist < 0 {
+            return None;
+        }
+        Some(self.pos.x - max_x_dist..=self.pos.x + max_x_dist)
+    }
+
+    /// Whether a beacon can't be at `point` as far as this sensor knows: it is covered, and
+    /// isn't the sensor or its beacon.
+    fn rules_out(&self, point: Point2) -> bool {
+        self.point_within_coverage_area(point) && point != self.closest_beacon && point != self.pos
+    }
+
+    /// Whether `point` is at least as close to the sensor as its beacon.
+    pub fn point_within_coverage_area(&self, point: Point2) -> bool {
+        point.manhattan(self.pos) <= self.manhattan_distance
+    }
+}
+
+/// Reads each sensor and its closest beacon, one per line.
+pub fn parse_sensors(text_input: &str) -> Result<Vec<Sensor>, ParseError> {
+    lines(text_input)
+        .map(|line| {
+            all_consuming(parse_sensor)(line)
+                .map(|(_, sensor)| sensor)
+                .map_err(|error| ParseError::from_nom(text_input, error))
+        })
+        .collect()
+}
+
+fn parse_sensor(input: &str) -> NomResult<'_, Sensor> {
+    let (input, _) = context("`Sensor at `", tag("Sensor at "))(input)?;
+    let (input, sensor_coord) = parse_point(input)?;
+    let (input, _) = context("`: closest beacon is at `", tag(": closest beacon is at "))(input)?;
+    let (input, beacon_coord) = parse_point(input)?;
+    Ok((input, Sensor::new(sensor_coord, beacon_coord)))
+}
+
+fn parse_point(input: &str) -> NomResult<'_, Point2> {
+    let (input, _) = context("`x=`", tag("x="))(input)?;
+
+    let (input, x_coord) = context(
+        "an x coordinate",
+        map_res(recognize(preceded(opt(tag("-")), digit1)), |num: &str| {
+            num.parse::<i64>()
+        }),
+    )(input)?;
+
+    let (input, _) = context("`, y=`", tag(", y="))(input)?;
+
+    let (input, y_coord) = context(
+        "a y coordinate",
+        map_res(recognize(preceded(opt(tag("-")), digit1)), |num: &str| {
+            num.parse::<i64>()
+        }),
+    )(input)?;
+
+    Ok((input, Point2::new(x_coord, y_coord)))
+}
+
+pub struct Day15 {
+    pub row: i64,
+    pub search_area: i64,
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Day15 {
+            row: 2000000,
+            search_area: 4000000,
+        }
+    }
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Parsed = Vec<Sensor>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Sensor>, Error> {
+        Ok(parse_sensors(input)?)
+    }
+
+    fn part_one(&self, sensors: &Vec<Sensor>) -> Result<Answer, Error> {
+        Ok(part_one(sensors, self.row)?.into())
+    }
+
+    fn part_two(&self, sensors: &Vec<Sensor>) -> Result<Answer, Error> {
+        Ok(part_two(sensors, 0, self.search_area)?.into())
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new(
+                "row",
+                "The row part one counts the positions without a beacon on",
+                self.row,
+            ),
+            Param::new(
+                "search_area",
+                "How far the distress beacon can be from 0 in x and y",
+                self.search_area,
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: i64) -> Result<(), String> {
+        match name {
+            "row" => self.row = value,
+            "search_area" => self.search_area = params::non_negative(value)?,
+            _ => return Err(format!("there is no parameter called `{}`", name)),
+        }
+        Ok(())
+    }
+}
+
+/// `size` sensors (at least four). Four of them sit diagonally around a distress beacon near the
+/// middle of the search area and between them cover all of it apart from the beacon, so part
+/// two always has an answer. The rest are scattered around without reaching it.
+impl Generate for Day15 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let area = self.search_area.max(8);
+        let middle = area * 3 / 8..=area * 5 / 8;
+        let distress = Point2::new(rng.range(middle.clone()), rng.range(middle));
+        // A sensor `reach` away diagonally covers everything in its quarter of the area apart
+        // from the distress beacon when it can see `2 * reach - 1` away
+        let reach = [distress.x, distress.y, area - distress.x, area - distress.y]
+            .into_iter()
+            .max()
+            .unwrap();
+        let mut sensors: Vec<Sensor> = [(1, 1), (1, -1), (-1, 1), (-1, -1)]
+            .into_iter()
+            .map(|(x, y)| {
+                let sensor_pos = distress + Point2::new(x, y) * reach;
+                Sensor::new(sensor_pos, sensor_pos + Point2::new(x * (2 * reach - 1), 0))
+            })
+            .collect();
+
+        let mut attempts = 0;
+        while sensors.len() < size && attempts < size * 100 {
+            attempts += 1;
+            let sensor_pos = Point2::new(rng.range(0..=area), rng.range(0..=area));
+            let max_range = (sensor_pos.manhattan(distress) - 1).min(area / 4);
+            if max_range < 1 {
+                continue;
+            }
+            let range = rng.range(1..=max_range);
+            let x = rng.range(-range..=range);
+            let y = (range - x.abs()) * if rng.chance(1, 2) { 1 } else { -1 };
+            let sensor = Sensor::new(sensor_pos, sensor_pos + Point2::new(x, y));
+
+            // Every sensor's beacon has to still be the closest one to it
+            let consistent = sensors.iter().all(|other| {
+                (other.closest_beacon == sensor.closest_beacon
+                    || (other.pos.manhattan(sensor.closest_beacon) > other.manhattan_distance
+                        && sensor.pos.manhattan(other.closest_beacon) > sensor.manhattan_distance))
+                    && other.pos != sensor.pos
+            });
+            if consistent {
+                sensors.push(sensor);
+            }
+        }
+        rng.shuffle(&mut sensors);
+
+        sensors
+            .iter()
+            .map(|sensor| {
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                    sensor.pos.x, sensor.pos.y, sensor.closest_beacon.x, sensor.closest_beacon.y
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// How many positions on row `row_num` can't hold a beacon.
+pub fn part_one(sensors: &[Sensor], row_num: i64) -> Result<u32, Error> {
+    let mut ranges = vec![];
+    for (index, sensor) in sensors.iter().enumerate() {
+        progress::update(index as u64, Some(sensors.len() as u64))?;
+        ranges.extend(sensor.coverage_on_row(row_num));
+    }
+    ranges.sort_unstable_by_key(|range| *range.start());
+
+    // Count the covered positions one run of overlapping ranges at a time
+    let mut num_covered: u64 = 0;
+    let mut ranges = ranges.into_iter();
+    if let Some(first) = ranges.next() {
+        let (mut start, mut end) = first.into_inner();
+        for range in ranges {
+            if *range.start() > end.saturating_add(1) {
+                num_covered += end.abs_diff(start) + 1;
+                start = *range.start();
+            }
+            end = end.max(*range.end());
+        }
+        num_covered += end.abs_diff(start) + 1;
+    }
+
+    // The sensors and beacons on the row are covered, but only ruled out if another sensor does
+    let mut known: Vec<Point2> = sensors
+        .iter()
+        .flat_map(|sensor| [sensor.pos, sensor.closest_beacon])
+        .filter(|point| point.y == row_num)
+        .collect();
+    known.sort_unstable();
+    known.dedup();
+    let num_known = known
+        .into_iter()
+        .filter(|point| !sensors.iter().any(|sensor| sensor.rules_out(*point)))
+        .count() as u64;
+
+    u32::try_from(num_covered - num_known)
+        .map_err(|_| Error::NoAnswer("too many positions are ruled out to count".to_string()))
+}
+
+/// The tuning frequency of the only position with x and y between `lower_bound` and
+/// `upper_bound` that no sensor covers.
+pub fn part_two(sensors: &[Sensor], lower_bound: i64, upper_bound: i64) -> Result<i64, Error> {
+    let num_rows = upper_bound.abs_diff(lower_bound).saturating_add(1);
+    for y in lower_bound..=upper_bound {
+        progress::update(y.abs_diff(lower_bound), Some(num_rows))?;
+        let mut x = lower_bound;
+        while x <= upper_bound {
+            let mut point_within_sensors = false;
+            for sensor in sensors {
+                point_within_sensors = sensor.point_within_coverage_area(Point2::new(x, y));
+
+                if point_within_sensors {
+                    // Jump x ahead until we are no longer within its range and then continue
+                    let max_x_reach = sensor.pos.x
+                        + i64::abs(sensor.manhattan_distance - i64::abs(sensor.pos.y - y));
+
+                    x = max_x_reach;
+                    break;
+                }
+            }
+
+            if !point_within_sensors {
+                return x
+                    .checked_mul(4000000)
+                    .and_then(|frequency| frequency.checked_add(y))
+                    .ok_or_else(|| {
+                        Error::NoAnswer("the tuning frequency is too large".to_string())
+                    });
+            }
+
+            x += 1;
+        }
+    }
+    Err(Error::NoAnswer(format!(
+        "every position from {} to {} is covered by a sensor",
+        lower_bound, upper_bound
+    )))
+}
+#[test]
+fn part_one_test() {
+    let text_input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    assert_eq!(
+        part_one(&parse_sensors(text_input).unwrap(), 10).unwrap(),
+        26
+    );
+}
+
+#[test]
+fn part_two_test() {
+    let text_input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    assert_eq!(
+        part_two(&parse_sensors(text_input).unwrap(), 0, 20).unwrap(),
+        56000011
+    );
+}
+
+#[test]
+fn test_point_within_coverage() {
+    let sensor = parse_sensor("Sensor at x=8, y=7: closest beacon is at x=2, y=10")
+        .unwrap()
+        .1;
+
+    println!("{:?}", sensor);
+
+    assert!(sensor.point_within_coverage_area(Point2::new(8, -2)));
+    assert!(sensor.point_within_coverage_area(Point2::new(17, 7)));
+    assert!(sensor.point_within_coverage_area(Point2::new(8, 16)));
+    assert!(sensor.point_within_coverage_area(Point2::new(-1, 7)));
+    assert!(!sensor.point_within_coverage_area(Point2::new(20, -2)));
+    assert!(!sensor.point_within_coverage_area(Point2::new(14, 1)));
+}
+
+#[test]
+fn test_params_far_from_the_sensors() {
+    let input_text = std::fs::read_to_string("./inputs/tests/day_15/example.txt").unwrap();
+    let sensors = parse_sensors(&input_text).unwrap();
+    let mut day_15 = Day15::default();
+
+    day_15.set_param("row", -i64::MAX).unwrap();
+    assert_eq!(day_15.part_one(&sensors).unwrap(), Answer::from(0u32));
+
+    // The first row already has a gap past the sensors' reach, at x=27
+    day_15.set_param("search_area", i64::MAX).unwrap();
+    assert_eq!(
+        day_15.part_two(&sensors).unwrap(),
+        Answer::from(27 * 4000000i64)
+    );
+}
+
+#[test]
+fn test_part_one_times_out() {
+    use crate::progress::{Budget, Cancelled};
+    use crate::solution::Part;
+
+    // Enough sensors for the budget to look at the clock
+    let sensors: Vec<Sensor> = (0..5000)
+        .map(|x| Sensor::new(Point2::new(x, 0), Point2::new(x, 1)))
+        .collect();
+    let budget = Budget::new().with_timeout(std::time::Duration::ZERO);
+
+    assert!(matches!(
+        budget.run(15, Part::One, || part_one(&sensors, 0)),
+        Err(Error::Cancelled(Cancelled::TimedOut(_)))
+    ));
+}
+
+/// Checks every position on the row one at a time, to compare against [`part_one`] counting
+/// whole ranges.
+#[cfg(test)]
+fn part_one_by_hand(sensors: &[Sensor], row_num: i64) -> u32 {
+    (-100..=100)
+        .map(|x| Point2::new(x, row_num))
+        .filter(|point| sensors.iter().any(|sensor| sensor.rules_out(*point)))
+        .count() as u32
+}
+
+/// Checks every position one at a time, to compare against [`part_two`] skipping ahead past each
+/// sensor's reach.
+#[cfg(test)]
+fn part_two_by_hand(sensors: &[Sensor], lower_bound: i64, upper_bound: i64) -> Option<i64> {
+    (lower_bound..=upper_bound)
+        .flat_map(|y| (lower_bound..=upper_bound).map(move |x| Point2::new(x, y)))
+        .find(|point| {
+            !sensors
+                .iter()
+                .any(|sensor| sensor.point_within_coverage_area(*point))
+        })
+        .map(|point| point.x * 4000000 + point.y)
+}
+
+#[cfg(test)]
+fn sensors_strategy() -> impl proptest::strategy::Strategy<Value = String> {
+    use proptest::strategy::Strategy;
+
+    let coordinate = -5..26i64;
+    let sensor = (
+        coordinate.clone(),
+        coordinate.clone(),
+        coordinate.clone(),
+        coordinate,
+    );
+    proptest::collection::vec(sensor, 1..8).prop_map(|sensors| {
+        sensors
+            .iter()
+            .map(|(x, y, beacon_x, beacon_y)| {
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                    x, y, beacon_x, beacon_y
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    })
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn differential_part_one(input_text in sensors_strategy(), row_num in -10..30i64) {
+        let sensors = parse_sensors(&input_text).unwrap();
+
+        proptest::prop_assert_eq!(
+            part_one(&sensors, row_num).unwrap(),
+            part_one_by_hand(&sensors, row_num)
+        );
+    }
+
+    #[test]
+    fn differential_part_two(input_text in sensors_strategy()) {
+        let sensors = parse_sensors(&input_text).unwrap();
+
+        proptest::prop_assert_eq!(
+            part_two(&sensors, 0, 20).ok(),
+            part_two_by_hand(&sensors, 0, 20)
+        );
+    }
+}
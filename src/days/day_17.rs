@@ -0,0 +1,510 @@
+use crate::cycle::{self, Cycle};
+use crate::error::{Error, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::input::{blocks, lines};
+use crate::params::{self, Param};
+use crate::search;
+use crate::solution::{Answer, Part, Solution};
+use crate::visualize::{Frame, Recorder, Visualize};
+use std::collections::HashSet;
+const ROCKS: &str = "####
+
+.#.
+###
+.#.
+
+..#
+..#
+###
+
+#
+#
+#
+#
+
+##
+##";
+
+/// How far below the top of the tower [`find_cycle`] starts out looking, which is plenty for
+/// real inputs. Short jet patterns can leave a deep gap open for a while, and then it looks
+/// further.
+const INITIAL_AIR_DEPTH: i32 = 100;
+
+struct GameGrid {
+    occupied_positions: HashSet<(i32, i32)>,
+    current_highest: u64,
+    current_moving_rock: Rock,
+    movement_index: usize,
+    rock_index: usize,
+}
+
+impl GameGrid {
+    fn new() -> Self {
+        GameGrid {
+            occupied_positions: HashSet::new(),
+            current_highest: 0,
+            current_moving_rock: Rock::default(),
+            movement_index: 0,
+            rock_index: 0,
+        }
+    }
+
+    /// Drops the next rock three rows above the tower and lets it fall until it comes to rest.
+    fn drop_rock(&mut self, base_rocks: &[Rock], movements: &[char]) {
+        let mut current_rock = base_rocks[self.rock_index].clone();
+        current_rock.pos.1 = self.current_highest as i32 + 3;
+
+        self.current_moving_rock = current_rock;
+        self.move_current_rock(movements);
+        self.rock_index = (self.rock_index + 1) % base_rocks.len();
+    }
+
+    fn move_current_rock(&mut self, movements: &[char]) {
+        while !self.current_moving_rock.stopped {
+            self.current_moving_rock
+                .perform_movement(movements[self.movement_index], &self.occupied_positions);
+
+            self.movement_index = (self.movement_index + 1) % movements.len();
+        }
+
+        self.current_moving_rock
+            .get_world_space_positions(self.current_moving_rock.pos)
+            .iter()
+            .for_each(|coord| {
+                self.occupied_positions.insert(*coord);
+
+                if coord.1 + 1 > self.current_highest as i32 {
+                    self.current_highest = coord.1 as u64 + 1;
+                }
+            });
+    }
+
+    /// Every empty position a falling rock could still get into, found by spreading out from the
+    /// row above the tower moving left, right and down, as `(x, depth below that row)`. Nothing
+    /// else can ever change, so together with the next jet and rock they decide how the rest of
+    /// the tower grows.
+    ///
+    /// Only the top `max_depth` rows are searched, otherwise a gap that nothing ever falls into
+    /// would be followed all the way down to the floor and never look the same twice.
+    fn reachable_air(&self, max_depth: i32) -> Vec<(i32, i32)> {
+        let top = self.current_highest as i32;
+        let is_air = |x: i32, y: i32| {
+            (0..7).contains(&x)
+                && y >= 0
+                && top - y <= max_depth
+                && !self.occupied_positions.contains(&(x, y))
+        };
+        let mut reachable: Vec<(i32, i32)> = search::bfs(
+            (0..7).map(|x| (x, top)),
+            |(x, y)| {
+                [(x - 1, *y), (x + 1, *y), (*x, y - 1)]
+                    .into_iter()
+                    .filter(|(x, y)| is_air(*x, *y))
+            },
+            |_| false,
+        )
+        .reached()
+        .map(|((x, y), _)| (*x, top - y))
+        .collect();
+        reachable.sort_unstable();
+        reachable
+    }
+
+    /// The top `rows` rows of the chamber, drawn like the puzzle does with the rock that moved
+    /// last as `@`. The floor comes into view while the tower is still short.
+    fn draw(&self, rows: usize) -> Frame {
+        let mut frame = Frame::new(9, rows, '.');
+        let top = i32::max(self.current_highest as i32 + 3, rows as i32 - 2);
+        let last_rock = self
+            .current_moving_rock
+            .get_world_space_positions(self.current_moving_rock.pos);
+
+        for row in 0..rows {
+            let y = top - row as i32;
+            if y == -1 {
+                (1..8).for_each(|x| frame.set(x, row, '-'));
+                frame.set(0, row, '+');
+                frame.set(8, row, '+');
+                continue;
+            }
+            frame.set(0, row, '|');
+            frame.set(8, row, '|');
+            for x in 0..7 {
+                if last_rock.contains(&(x, y)) {
+                    frame.set(x as usize + 1, row, '@');
+                } else if self.occupied_positions.contains(&(x, y)) {
+                    frame.set(x as usize + 1, row, '#');
+                }
+            }
+        }
+        frame
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Rock {
+    rock_positions: Vec<(i32, i32)>, // Model space rock piece positions referenced to the bottom left
+    pos: (i32, i32),
+    width: u64,
+    stopped: bool,
+}
+
+impl Rock {
+    fn perform_movement(&mut self, action: char, game_grid: &HashSet<(i32, i32)>) {
+        let movement: i32 = match action {
+            '<' => -1,
+            '>' => 1,
+            _ => 0,
+        };
+
+        let new_x_pos = self.pos.0 + movement;
+        if new_x_pos >= 0
+            && new_x_pos + (self.width - 1) as i32 <= 6
+            && !self.collides_with_grid((new_x_pos, self.pos.1), game_grid)
+        {
+            self.pos.0 = new_x_pos;
+        }
+
+        let new_y_pos: i32 = self.pos.1 - 1;
+
+        if new_y_pos != -1 && !self.collides_with_grid((self.pos.0, new_y_pos), game_grid) {
+            self.pos.1 = new_y_pos;
+        } else {
+            self.stopped = true;
+        }
+    }
+
+    fn get_world_space_positions(&self, coord: (i32, i32)) -> Vec<(i32, i32)> {
+        self.rock_positions
+            .iter()
+            .map(|(x, y)| (coord.0 + x, coord.1 + y))
+            .collect::<Vec<(i32, i32)>>()
+    }
+
+    fn collides_with_grid(&self, coord: (i32, i32), game_grid: &HashSet<(i32, i32)>) -> bool {
+        for ws_coord in self.get_world_space_positions(coord) {
+            if game_grid.contains(&ws_coord) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+pub struct Day17 {
+    pub part_one_rocks: u64,
+    pub part_two_rocks: u64,
+}
+
+impl Default for Day17 {
+    fn default() -> Self {
+        Day17 {
+            part_one_rocks: 2022,
+            part_two_rocks: 1000000000000,
+        }
+    }
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Parsed = Vec<char>;
+
+    fn parse(&self, input: &str) -> Result<Vec<char>, Error> {
+        Ok(parse_movements(input)?)
+    }
+
+    fn part_one(&self, movements: &Vec<char>) -> Result<Answer, Error> {
+        Ok(tower_height(movements, self.part_one_rocks)?.into())
+    }
+
+    fn part_two(&self, movements: &Vec<char>) -> Result<Answer, Error> {
+        Ok(tower_height(movements, self.part_two_rocks)?.into())
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new(
+                "part_one_rocks",
+                "How many rocks fall in part one",
+                self.part_one_rocks as i64,
+            ),
+            Param::new(
+                "part_two_rocks",
+                "How many rocks fall in part two",
+                self.part_two_rocks as i64,
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: i64) -> Result<(), String> {
+        match name {
+            "part_one_rocks" => self.part_one_rocks = params::non_negative(value)?,
+            "part_two_rocks" => self.part_two_rocks = params::non_negative(value)?,
+            _ => return Err(format!("there is no parameter called `{}`", name)),
+        }
+        Ok(())
+    }
+}
+
+/// A jet pattern `size` jets long.
+impl Generate for Day17 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| if rng.chance(1, 2) { '<' } else { '>' })
+            .collect()
+    }
+}
+
+const VIEW_ROWS: usize = 40;
+
+/// One frame per rock that comes to rest, looking at the top of the tower. Part two only ever
+/// simulates rocks until the tower starts repeating, so that is as far as it goes.
+impl Visualize for Day17 {
+    fn visualize(
+        &self,
+        movements: &Self::Parsed,
+        part: Part,
+        recorder: &mut Recorder,
+    ) -> Result<(), Error> {
+        let base_rocks = parse_rocks(ROCKS);
+        let num_rocks = match part {
+            Part::One => self.part_one_rocks,
+            Part::Two => {
+                let cycle = find_cycle(&base_rocks, movements, INITIAL_AIR_DEPTH)?;
+                cycle.start + cycle.length
+            }
+        };
+
+        let mut game_grid = GameGrid::new();
+        for rock_num in 1..=num_rocks {
+            game_grid.drop_rock(&base_rocks, movements);
+
+            let draw = || {
+                game_grid.draw(VIEW_ROWS).with_caption(format!(
+                    "{} rocks, {} units tall",
+                    rock_num, game_grid.current_highest
+                ))
+            };
+            if rock_num == num_rocks {
+                recorder.last(draw);
+            } else if !recorder.step(draw) {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads the jet pattern, one `<` or `>` per jet.
+pub fn parse_movements(input_text: &str) -> Result<Vec<char>, ParseError> {
+    let jets = input_text.trim_end();
+    if jets.is_empty() {
+        return Err(ParseError::at(input_text, jets, "`<` or `>`"));
+    }
+    if let Some(offset) = jets.find(|c| c != '<' && c != '>') {
+        return Err(ParseError::at(input_text, &jets[offset..], "`<` or `>`"));
+    }
+    Ok(jets.chars().collect())
+}
+
+/// How tall the tower is after `num_rocks` rocks have fallen. This is part one with 2022 rocks
+/// and part two with 1000000000000.
+pub fn tower_height(movements: &[char], num_rocks: u64) -> Result<u64, Error> {
+    find_cycle(&parse_rocks(ROCKS), movements, INITIAL_AIR_DEPTH)?
+        .extrapolate(num_rocks)
+        .ok_or_else(|| {
+            Error::NoAnswer(format!(
+                "the tower gets too tall to measure after {} rocks",
+                num_rocks
+            ))
+        })
+}
+
+fn parse_rocks(input: &str) -> Vec<Rock> {
+    let mut base_rocks = blocks(input)
+        .into_iter()
+        .map(|rock_text| Rock {
+            rock_positions: lines(rock_text)
+                .rev()
+                .enumerate()
+                .flat_map(|(i, rock_row)| {
+                    rock_row
+                        .chars()
+                        .enumerate()
+                        .filter(|(_, block)| *block == '#')
+                        .map(|(j, _)| (j as i32, i as i32))
+                        .collect::<Vec<(i32, i32)>>()
+                })
+                .collect::<Vec<(i32, i32)>>(),
+            pos: (2, 0),
+            width: 0,
+            stopped: false,
+        })
+        .collect::<Vec<Rock>>();
+
+    for rock in &mut base_rocks {
+        let mut largest_width: u64 = 0;
+
+        for (x, _) in rock.rock_positions.iter() {
+            if (x + 1) as u64 > largest_width {
+                largest_width = (x + 1) as u64;
+            }
+        }
+
+        rock.width = largest_width;
+    }
+    base_rocks
+}
+
+/// Drops rocks until the shape of the top `air_depth` rows of the tower, the next jet and the
+/// next rock all repeat, tracking the height of the tower after each rock.
+///
+/// That is only a real cycle if no rock fell further than `air_depth` below the top, since
+/// anything deeper could still have been different. If one did, this starts again looking
+/// twice as deep as that rock fell.
+fn find_cycle(
+    base_rocks: &[Rock],
+    movements: &[char],
+    mut air_depth: i32,
+) -> Result<Cycle<u64>, Error> {
+    loop {
+        let mut deepest_fall = 0;
+        let cycle = cycle::find(
+            GameGrid::new(),
+            |game_grid| {
+                let top = game_grid.current_highest as i32;
+                game_grid.drop_rock(base_rocks, movements);
+                deepest_fall = deepest_fall.max(top - game_grid.current_moving_rock.pos.1);
+            },
+            |game_grid| {
+                (
+                    game_grid.reachable_air(air_depth),
+                    game_grid.movement_index,
+                    game_grid.rock_index,
+                )
+            },
+            |game_grid| game_grid.current_highest,
+        )?;
+        if deepest_fall < air_depth {
+            return Ok(cycle);
+        }
+        air_depth = deepest_fall * 2;
+    }
+}
+
+#[test]
+fn part_one_test() {
+    let input_text = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    assert_eq!(
+        3068,
+        tower_height(&parse_movements(input_text).unwrap(), 2022).unwrap()
+    );
+    // assert_eq!(3068, part_one(input_text, 3));
+}
+#[test]
+fn part_two_test() {
+    let input_text = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    assert_eq!(
+        1514285714288,
+        tower_height(&parse_movements(input_text).unwrap(), 1000000000000).unwrap()
+    );
+}
+
+#[test]
+fn test_visualize_first_rocks() {
+    let input_text = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+    let mut frames: Vec<Frame> = vec![];
+    let mut recorder = Recorder::new(&mut frames, 1, Some(2));
+
+    Day17::default()
+        .visualize(
+            &parse_movements(input_text).unwrap(),
+            Part::One,
+            &mut recorder,
+        )
+        .unwrap();
+
+    assert_eq!(recorder.finish().unwrap(), 2);
+    assert_eq!(frames[1].caption, "2 rocks, 4 units tall");
+    let bottom: Vec<String> = frames[1]
+        .to_string()
+        .lines()
+        .skip(VIEW_ROWS - 5)
+        .map(str::to_string)
+        .collect();
+    assert_eq!(
+        bottom,
+        [
+            "|...@...|",
+            "|..@@@..|",
+            "|...@...|",
+            "|..####.|",
+            "+-------+"
+        ]
+    );
+}
+
+/// Drops every rock one after another, to compare against [`tower_height`] extrapolating from
+/// the first cycle.
+#[cfg(test)]
+fn simulated_height(movements: &[char], num_rocks: u64) -> u64 {
+    let base_rocks = parse_rocks(ROCKS);
+    let mut game_grid = GameGrid::new();
+    for _ in 0..num_rocks {
+        game_grid.drop_rock(&base_rocks, movements);
+    }
+    game_grid.current_highest
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::prelude::ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn differential_tower_height(input_text in "[<>]{1,40}", num_rocks in 0..2500u64) {
+        let movements = parse_movements(&input_text).unwrap();
+
+        proptest::prop_assert_eq!(
+            tower_height(&movements, num_rocks).unwrap(),
+            simulated_height(&movements, num_rocks)
+        );
+    }
+}
+
+#[test]
+fn test_tower_too_tall() {
+    let movements = parse_movements("<").unwrap();
+
+    assert_eq!(
+        tower_height(&movements, i64::MAX as u64)
+            .unwrap_err()
+            .to_string(),
+        "no answer: the tower gets too tall to measure after 9223372036854775807 rocks"
+    );
+}
+
+#[test]
+fn test_short_jet_pattern_extrapolates() {
+    // Only looking at the top 20 rows used to mistake this for a cycle
+    let movements = parse_movements("<><><<>>>><<>>>>><<>><<>").unwrap();
+
+    assert_eq!(tower_height(&movements, 59).unwrap(), 99);
+}
+
+#[test]
+fn test_cycle_search_looks_deeper_when_rocks_fall_further() {
+    // Starting with the top 20 rows, some rock falls further than that before a cycle turns up
+    let movements = parse_movements("<><><<>>>><<>>>>><<>><<>").unwrap();
+    let cycle = find_cycle(&parse_rocks(ROCKS), &movements, 20).unwrap();
+
+    assert_eq!(
+        cycle.extrapolate(59),
+        Some(simulated_height(&movements, 59))
+    );
+    assert_eq!(
+        cycle.extrapolate(5000),
+        Some(simulated_height(&movements, 5000))
+    );
+}
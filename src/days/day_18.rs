@@ -0,0 +1,283 @@
+use crate::error::{Error, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::input::lines;
+use crate::point::Point3;
+use crate::search;
+use crate::solution::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+
+/// How far from 0 a cube can be, so that doubling its coordinates and looking just past the
+/// cubes can't overflow.
+pub const MAX_COORDINATE: i64 = i64::MAX / 4;
+
+/// Every coordinate is doubled so that the centres of the faces, half way between two cubes, are
+/// still whole numbers.
+#[derive(Debug, Default)]
+pub struct Cube {
+    center: Point3,
+    surface_centers: [Point3; 6],
+}
+
+impl Cube {
+    /// A cube at `position`, whose coordinates can be at most [`MAX_COORDINATE`] from 0.
+    pub fn new(position: Point3) -> Self {
+        let center = position * 2;
+        Cube {
+            center,
+            surface_centers: Point3::UNITS.map(|unit| center + unit),
+        }
+    }
+
+    pub fn position(&self) -> Point3 {
+        Point3::new(self.center.x / 2, self.center.y / 2, self.center.z / 2)
+    }
+}
+
+struct ConnectedCubes {
+    connection_point: Point3,
+    connected_cubes_indices: [usize; 2], // The indices in the cube array that is contains this connection point (mid point of the faces)
+    frequency: u32,
+}
+
+struct BoundingCube {
+    min: Point3,
+    max: Point3,
+}
+
+impl BoundingCube {
+    fn new(cubes: &[Cube]) -> Self {
+        let (min, max) =
+            Point3::bounds(cubes.iter().flat_map(|cube| cube.surface_centers)).unwrap_or_default();
+        Self { min, max }
+    }
+
+    fn contains_point(&self, point: &Point3) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    /// The box `by` units bigger on every side.
+    fn grown(&self, by: i64) -> BoundingCube {
+        let margin = Point3::new(by, by, by);
+        BoundingCube {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+
+    #[cfg(test)]
+    fn get_coordinate_boundaries(&self) -> (Point3, Point3) {
+        (self.min, self.max)
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Parsed = Vec<Cube>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Cube>, Error> {
+        Ok(parse_coordinates(input)?)
+    }
+
+    fn part_one(&self, cubes: &Vec<Cube>) -> Result<Answer, Error> {
+        Ok(part_one(cubes).into())
+    }
+
+    fn part_two(&self, cubes: &Vec<Cube>) -> Result<Answer, Error> {
+        Ok(part_two(cubes).into())
+    }
+}
+
+/// `size` different cubes packed loosely into a box, so that some air gets trapped.
+impl Generate for Day18 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        // The box has room for about twice as many cubes
+        let side = (1..).find(|side| side * side * side >= 2 * size).unwrap() as i64;
+        let mut seen = HashSet::new();
+        let mut cubes = vec![];
+        while cubes.len() < size {
+            let cube = Point3::new(
+                rng.range(1..=side),
+                rng.range(1..=side),
+                rng.range(1..=side),
+            );
+            if seen.insert(cube) {
+                cubes.push(cube.to_string());
+            }
+        }
+        cubes.join("\n")
+    }
+}
+
+/// Reads the position of each cube, one `x,y,z` per line.
+pub fn parse_coordinates(input: &str) -> Result<Vec<Cube>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let position = Point3::parse(input, line)?;
+            let coordinates = [position.x, position.y, position.z];
+            if let Some((text, _)) = line
+                .split(',')
+                .zip(coordinates)
+                .find(|(_, coordinate)| coordinate.unsigned_abs() > MAX_COORDINATE as u64)
+            {
+                return Err(ParseError::at(
+                    input,
+                    text.trim(),
+                    format!(
+                        "a coordinate from -{} to {}",
+                        MAX_COORDINATE, MAX_COORDINATE
+                    ),
+                ));
+            }
+            Ok(Cube::new(position))
+        })
+        .collect()
+}
+
+fn get_side_frequency(cubes: &[Cube]) -> HashMap<Point3, ConnectedCubes> {
+    let mut side_frequency: HashMap<Point3, ConnectedCubes> = HashMap::new();
+
+    cubes.iter().enumerate().for_each(|(index, cube)| {
+        for coord in &cube.surface_centers {
+            side_frequency
+                .entry(*coord)
+                .and_modify(|connected_cube| {
+                    connected_cube.frequency += 1;
+                    connected_cube.connected_cubes_indices[1] = index;
+                })
+                .or_insert(ConnectedCubes {
+                    connection_point: *coord,
+                    connected_cubes_indices: [index, 0],
+                    frequency: 1,
+                });
+        }
+    });
+
+    side_frequency
+}
+
+/// How many faces of the cubes aren't touching another cube.
+pub fn part_one(cubes: &[Cube]) -> u32 {
+    get_side_frequency(cubes)
+        .iter()
+        .filter(|(_, connected_cube)| connected_cube.frequency == 1)
+        .count() as u32
+}
+
+/// How many faces of the cubes can be reached by air from outside the droplet.
+pub fn part_two(cubes: &[Cube]) -> u32 {
+    let side_frequency = get_side_frequency(cubes);
+
+    // Every face is at an odd coordinate along its axis, so one step outside the faces' bounding
+    // box is where the air pockets around the droplet are. They are all connected, so a single
+    // search from a corner reaches every pocket that the outside air can get to.
+    let outside = &BoundingCube::new(cubes).grown(1);
+    let walls = &side_frequency;
+    let reachable = search::bfs(
+        [outside.min],
+        |pocket| {
+            // The air can move to the next pocket in each direction unless a cube's face is in
+            // the way
+            let pocket = *pocket;
+            Point3::UNITS
+                .into_iter()
+                .filter(move |unit| !walls.contains_key(&(pocket + *unit)))
+                .map(move |unit| pocket + unit * 2)
+                .filter(|next| outside.contains_point(next))
+        },
+        |_| false,
+    );
+
+    side_frequency
+        .values()
+        .filter(|cube| cube.frequency == 1)
+        .filter(|cube| {
+            let cube_center = cubes[cube.connected_cubes_indices[0]].center;
+            // The face is half way to the air pocket next to it
+            let air_pocket = cube_center + (cube.connection_point - cube_center) * 2;
+            reachable.contains(&air_pocket)
+        })
+        .count() as u32
+}
+
+#[test]
+fn test_part_one() {
+    let input_text = "2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5";
+
+    assert_eq!(part_one(&parse_coordinates(input_text).unwrap()), 64);
+}
+
+#[test]
+fn test_part_two() {
+    let input_text = "2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5";
+
+    assert_eq!(part_two(&parse_coordinates(input_text).unwrap()), 58);
+}
+
+#[test]
+fn test_coordinates_too_far_out() {
+    assert_eq!(
+        parse_coordinates("1,2,3\n9223372036854775807,0,0").unwrap_err(),
+        ParseError::new(
+            2,
+            1,
+            format!(
+                "a coordinate from -{} to {}",
+                MAX_COORDINATE, MAX_COORDINATE
+            )
+        )
+    );
+    assert!(parse_coordinates(&format!("0,{},0", -MAX_COORDINATE)).is_ok());
+}
+
+#[test]
+fn test_bounding_box() {
+    let input_text = "2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5";
+    let cubes = parse_coordinates(input_text).unwrap();
+    let bounding_cube = BoundingCube::new(&cubes);
+    let (min, max) = bounding_cube.get_coordinate_boundaries();
+
+    // Doubled, so 0.5 to 3.5 across x and y and 0.5 to 6.5 along z
+    assert_eq!(min, Point3::new(1, 1, 1));
+    assert_eq!(max, Point3::new(7, 7, 13));
+}
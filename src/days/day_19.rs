@@ -0,0 +1,662 @@
+use crate::error::{Error, NomResult, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::input::lines;
+use crate::params::{self, Param};
+use crate::progress;
+use crate::solution::{Answer, Solution};
+use nom::{
+    bytes::complete::tag, character::complete, combinator::all_consuming, error::context,
+    sequence::delimited,
+};
+use std::collections::HashMap;
+
+const RESOURCE_TYPES: [&str; 4] = ["ore", "clay", "obsidian", "geode"];
+const ROBOT_TYPES: [&str; 4] = ["ore_robot", "clay_robot", "obsidian_robot", "geode_robot"];
+
+#[derive(Debug, Clone)]
+pub struct ResourceRequirements {
+    resource: &'static str,
+    cost: u32,
+}
+
+/// What each kind of robot costs to build.
+#[derive(Debug, Clone)]
+pub struct Blueprint {
+    id: u32,
+    robot_costs: HashMap<&'static str, Vec<ResourceRequirements>>,
+}
+
+impl Blueprint {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+#[derive(Clone)]
+struct State {
+    resources: HashMap<&'static str, u32>,
+    robots: HashMap<&'static str, u32>,
+}
+impl State {
+    fn new() -> Self {
+        let mut resources: HashMap<&'static str, u32> = HashMap::new();
+        for resource in RESOURCE_TYPES.iter() {
+            resources.insert(resource, 0);
+        }
+        let mut robots: HashMap<&'static str, u32> = HashMap::new();
+        for robot_type in ROBOT_TYPES.iter() {
+            robots.insert(robot_type, 0);
+        }
+        robots.insert("ore_robot", 1); // always start with one ore robot
+                                       //
+        State { resources, robots }
+    }
+
+    fn get_num_geodes(&self) -> u32 {
+        *self.resources.get("geode").unwrap()
+    }
+
+    fn increase_resources(&mut self) {
+        for (resource, robot) in RESOURCE_TYPES.iter().zip(ROBOT_TYPES.iter()) {
+            let count = self.resources.entry(resource).or_insert(0);
+            let num_robots = self
+                .robots
+                .get(robot)
+                .expect("All robots should exist inside the inventory.robots hashmap"); // We know that the value exists
+            *count += num_robots;
+            //println!("{} {} collecting robot collected {} {}. You now have {} {}", num_robots, resource, num_robots, resource, *count, resource);
+        }
+    }
+
+    fn build_robot(
+        &mut self,
+        robot_type: Option<&'static str>,
+        robot_costs: &HashMap<&'static str, Vec<ResourceRequirements>>,
+    ) {
+        if let Some(robot_type) = robot_type {
+            for requirement in robot_costs
+                .get(robot_type)
+                .expect("The robot type must exist")
+            {
+                let num_resource = self.resources.entry(requirement.resource).or_insert(0);
+                *num_resource -= requirement.cost;
+            }
+
+            let robot_count = self.robots.entry(robot_type).or_insert(0);
+            *robot_count += 1;
+            //println!("Purchased: {}, with {:?}", robot_type, robot_costs.get(robot_type).expect("The robot type must exist"));
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Simulation<'a> {
+    blueprint: &'a Blueprint,
+    max_resource_requirements: HashMap<&'static str, u32>,
+}
+
+impl<'a> Simulation<'a> {
+    fn new(blueprint: &'a Blueprint) -> Self {
+        // create the hashmap containing the max amount of each resource that is required to build
+        // a robot
+        let mut max_resource_requirements: HashMap<&'static str, u32> = HashMap::new();
+        for costs in blueprint.robot_costs.values() {
+            for resource_requirement in costs {
+                let resource_cost = max_resource_requirements
+                    .entry(resource_requirement.resource)
+                    .or_insert(1);
+                if *resource_cost < resource_requirement.cost {
+                    *resource_cost = resource_requirement.cost;
+                }
+            }
+        }
+        max_resource_requirements.insert("geode", u32::MAX); //geodes don't have a max as we always
+                                                             //want to buy them
+
+        Simulation {
+            blueprint,
+            max_resource_requirements,
+        }
+    }
+
+    fn run(
+        &mut self,
+        state: &State,
+        robot_built_last_round: Option<&'static str>,
+        time_remaining: u32,
+        most_num_geodes: &mut u32,
+    ) -> Result<(), Error> {
+        progress::check()?;
+        let mut state = state.clone();
+
+        if time_remaining == 0 {
+            // simulation for this branch is done
+
+            let current_branch_num_geodes = state.get_num_geodes();
+            if *most_num_geodes < current_branch_num_geodes {
+                *most_num_geodes = current_branch_num_geodes;
+            }
+
+            return Ok(());
+        }
+
+        state.build_robot(robot_built_last_round, &self.blueprint.robot_costs);
+        let branches = self.try_build_robots(&state);
+
+        state.increase_resources();
+
+        for possible_robot in branches {
+            // Only run if the maximum possible geodes that can be built from the current state is
+            // greater than the current most_num_geodes (branch and bound)
+
+            if self.get_max_bound(&state, possible_robot, time_remaining - 1) > *most_num_geodes {
+                self.run(&state, possible_robot, time_remaining - 1, most_num_geodes)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn get_max_bound(
+        &self,
+        state: &State,
+        robot_decision_branch: Option<&'static str>,
+        time_remaining: u32,
+    ) -> u32 {
+        // Assume that we either build a geode robot (have enough obsidian) or we build obsidian
+        let mut state = state.clone();
+        state.build_robot(robot_decision_branch, &self.blueprint.robot_costs);
+        let geode_obsidian_cost = self
+            .blueprint
+            .robot_costs
+            .get("geode_robot")
+            .unwrap()
+            .iter()
+            .find(|el| el.resource == "obsidian")
+            .unwrap()
+            .cost;
+
+        for _ in (0..time_remaining).rev() {
+            state.increase_resources();
+
+            if state.resources.get("obsidian").unwrap() >= &geode_obsidian_cost {
+                let num_resource = state.resources.entry("obsidian").or_insert(0);
+                *num_resource -= geode_obsidian_cost;
+
+                let robot_count = state.robots.entry("geode_robot").or_insert(0);
+                *robot_count += 1;
+            } else {
+                let robot_count = state.robots.entry("obsidian_robot").or_insert(0);
+                *robot_count += 1;
+            }
+        }
+
+        state.get_num_geodes()
+    }
+
+    fn try_build_robots(&self, state: &State) -> Vec<Option<&'static str>> {
+        let mut possible_robot_decisions: Vec<Option<&'static str>> = vec![];
+        // Reversed so that geode gets checked first. This is because we always want to get a geode robot if we can afford it
+        for (robot, resource) in ROBOT_TYPES.iter().zip(RESOURCE_TYPES).rev() {
+            if self.can_afford_robot(robot, state)
+                && self.should_buy_robot(&state.robots, robot, resource)
+            {
+                possible_robot_decisions.push(Some(*robot));
+                if *robot == "geode_robot" {
+                    return possible_robot_decisions;
+                }
+            }
+        }
+
+        possible_robot_decisions.push(None); // the case where we just don't buy any robots
+        possible_robot_decisions
+    }
+
+    fn can_afford_robot(&self, robot_type: &str, state: &State) -> bool {
+        for requirement in self
+            .blueprint
+            .robot_costs
+            .get(robot_type)
+            .expect("The robot type must exist")
+        {
+            let num_resource = state
+                .resources
+                .get(requirement.resource)
+                .expect("The resource must exist");
+
+            if *num_resource < requirement.cost {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn should_buy_robot(
+        &self,
+        robots: &HashMap<&'static str, u32>,
+        robot_type: &str,
+        resource: &str,
+    ) -> bool {
+        let num_robots = *robots.get(robot_type).unwrap();
+        let at_capacity = num_robots >= *self.max_resource_requirements.get(resource).unwrap();
+        if !at_capacity {
+            return true;
+        }
+        false
+    }
+}
+
+/// Reads the blueprints, one per line.
+pub fn parse_blueprints(input_text: &str) -> Result<Vec<Blueprint>, ParseError> {
+    lines(input_text)
+        .map(|line| {
+            all_consuming(parse_blueprint)(line)
+                .map(|(_, blueprint)| blueprint)
+                .map_err(|error| ParseError::from_nom(input_text, error))
+        })
+        .collect()
+}
+
+/// A robot cost, e.g. `cost(" Each ore robot costs ", " ore.")` for ` Each ore robot costs 4 ore.`.
+fn cost<'a>(
+    prefix: &'static str,
+    prefix_description: &'static str,
+    suffix: &'static str,
+    suffix_description: &'static str,
+) -> impl FnMut(&'a str) -> NomResult<'a, u32> {
+    delimited(
+        context(prefix_description, tag(prefix)),
+        context("a cost", complete::u32),
+        context(suffix_description, tag(suffix)),
+    )
+}
+
+fn parse_blueprint(input: &str) -> NomResult<'_, Blueprint> {
+    let mut robot_costs: HashMap<&'static str, Vec<ResourceRequirements>> = HashMap::new();
+
+    let (input, index) = cost("Blueprint ", "`Blueprint `", ":", "`:`")(input)?;
+
+    let (input, ore_robot_cost) = cost(
+        " Each ore robot costs ",
+        "` Each ore robot costs `",
+        " ore.",
+        "` ore.`",
+    )(input)?;
+    let ore_robot_requirements = vec![ResourceRequirements {
+        resource: "ore",
+        cost: ore_robot_cost,
+    }];
+    robot_costs.insert("ore_robot", ore_robot_requirements);
+
+    let (input, clay_robot_cost) = cost(
+        " Each clay robot costs ",
+        "` Each clay robot costs `",
+        " ore.",
+        "` ore.`",
+    )(input)?;
+    let clay_robot_requirements = vec![ResourceRequirements {
+        resource: "ore",
+        cost: clay_robot_cost,
+    }];
+    robot_costs.insert("clay_robot", clay_robot_requirements);
+
+    let (input, obsidian_robot_cost_ore) = cost(
+        " Each obsidian robot costs ",
+        "` Each obsidian robot costs `",
+        " ore",
+        "` ore`",
+    )(input)?;
+    let (input, obsidian_robot_cost_clay) = cost(" and ", "` and `", " clay.", "` clay.`")(input)?;
+    let obsidian_robot_requirements = vec![
+        ResourceRequirements {
+            resource: "ore",
+            cost: obsidian_robot_cost_ore,
+        },
+        ResourceRequirements {
+            resource: "clay",
+            cost: obsidian_robot_cost_clay,
+        },
+    ];
+    robot_costs.insert("obsidian_robot", obsidian_robot_requirements);
+
+    let (input, geode_robot_cost_ore) = cost(
+        " Each geode robot costs ",
+        "` Each geode robot costs `",
+        " ore",
+        "` ore`",
+    )(input)?;
+    let (input, geode_robot_cost_obsidian) =
+        cost(" and ", "` and `", " obsidian.", "` obsidian.`")(input)?;
+    let geode_robot_requirements = vec![
+        ResourceRequirements {
+            resource: "ore",
+            cost: geode_robot_cost_ore,
+        },
+        ResourceRequirements {
+            resource: "obsidian",
+            cost: geode_robot_cost_obsidian,
+        },
+    ];
+    robot_costs.insert("geode_robot", geode_robot_requirements);
+
+    let blueprint = Blueprint {
+        id: index,
+        robot_costs,
+    };
+
+    Ok((input, blueprint))
+}
+
+pub struct Day19 {
+    pub part_one_minutes: u32,
+    pub part_two_minutes: u32,
+    pub part_two_blueprints: usize,
+}
+
+impl Default for Day19 {
+    fn default() -> Self {
+        Day19 {
+            part_one_minutes: 24,
+            part_two_minutes: 32,
+            part_two_blueprints: 3,
+        }
+    }
+}
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Parsed = Vec<Blueprint>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Blueprint>, Error> {
+        Ok(parse_blueprints(input)?)
+    }
+
+    fn part_one(&self, blueprints: &Vec<Blueprint>) -> Result<Answer, Error> {
+        Ok(part_one(blueprints, self.part_one_minutes)?.into())
+    }
+
+    fn part_two(&self, blueprints: &Vec<Blueprint>) -> Result<Answer, Error> {
+        Ok(part_two(blueprints, self.part_two_minutes, self.part_two_blueprints)?.into())
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new(
+                "part_one_minutes",
+                "How many minutes each blueprint has in part one",
+                self.part_one_minutes.into(),
+            ),
+            Param::new(
+                "part_two_minutes",
+                "How many minutes each blueprint has in part two",
+                self.part_two_minutes.into(),
+            ),
+            Param::new(
+                "part_two_blueprints",
+                "How many blueprints are left uneaten in part two",
+                self.part_two_blueprints as i64,
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: i64) -> Result<(), String> {
+        match name {
+            "part_one_minutes" => self.part_one_minutes = params::non_negative(value)?,
+            "part_two_minutes" => self.part_two_minutes = params::non_negative(value)?,
+            "part_two_blueprints" => self.part_two_blueprints = params::non_negative(value)?,
+            _ => return Err(format!("there is no parameter called `{}`", name)),
+        }
+        Ok(())
+    }
+}
+
+/// `size` blueprints, with costs in the same ranges as the real ones.
+impl Generate for Day19 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (1..=size.max(1))
+            .map(|id| {
+                format!(
+                    "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+                    id,
+                    rng.range(2..=4),
+                    rng.range(2..=4),
+                    rng.range(2..=4),
+                    rng.range(5..=20),
+                    rng.range(2..=4),
+                    rng.range(5..=20)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// The quality levels of every blueprint given `num_minutes`, added together.
+pub fn part_one(blueprints: &[Blueprint], num_minutes: u32) -> Result<u32, Error> {
+    let mut total_quality_levels: u32 = 0;
+    for (index, blueprint) in blueprints.iter().enumerate() {
+        progress::update(index as u64, Some(blueprints.len() as u64))?;
+        total_quality_levels = blueprint
+            .id
+            .checked_mul(process_blueprint(blueprint, num_minutes)?)
+            .and_then(|quality_level| total_quality_levels.checked_add(quality_level))
+            .ok_or_else(overflow)?;
+    }
+
+    Ok(total_quality_levels)
+}
+
+/// The most geodes each of the first `num_blueprints` blueprints can open in `num_minutes`,
+/// multiplied together.
+pub fn part_two(
+    blueprints: &[Blueprint],
+    num_minutes: u32,
+    num_blueprints: usize,
+) -> Result<u32, Error> {
+    let mut product_num_geodes: u32 = 1;
+    let num_blueprints = blueprints.len().min(num_blueprints);
+    for (index, blueprint) in blueprints.iter().take(num_blueprints).enumerate() {
+        progress::update(index as u64, Some(num_blueprints as u64))?;
+        product_num_geodes = product_num_geodes
+            .checked_mul(process_blueprint(blueprint, num_minutes)?)
+            .ok_or_else(overflow)?;
+    }
+
+    Ok(product_num_geodes)
+}
+
+fn overflow() -> Error {
+    Error::NoAnswer("the answer gets too large to count".to_string())
+}
+
+/// The most geodes `blueprint` can open in `num_minutes`.
+pub fn process_blueprint(blueprint: &Blueprint, num_minutes: u32) -> Result<u32, Error> {
+    let mut simulation = Simulation::new(blueprint);
+    let state = State::new();
+    let mut most_num_geodes = 0;
+
+    simulation.run(&state, None, num_minutes, &mut most_num_geodes)?;
+    Ok(most_num_geodes)
+}
+
+#[test]
+fn test_part_one() {
+    let input_text = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
+
+    assert_eq!(
+        part_one(&parse_blueprints(input_text).unwrap(), 24).unwrap(),
+        33
+    );
+}
+
+#[test]
+fn test_part_two() {
+    let input_text = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
+
+    assert_eq!(
+        part_two(&parse_blueprints(input_text).unwrap(), 32, 3).unwrap(),
+        56 * 62
+    );
+}
+
+#[test]
+fn test_first_blueprint() {
+    let input_text = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.";
+
+    assert_eq!(
+        part_one(&parse_blueprints(input_text).unwrap(), 24).unwrap(),
+        9
+    );
+}
+
+#[test]
+fn test_second_blueprint() {
+    dbg!("Hello this terst is starting");
+    let input_text = "Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
+
+    assert_eq!(
+        part_one(&parse_blueprints(input_text).unwrap(), 24).unwrap(),
+        24
+    );
+}
+
+/// Tries building every robot that can be afforded (or nothing) every minute, remembering the
+/// best result from each state, to compare against the pruning in [`Simulation`].
+#[cfg(test)]
+fn exhaustive_max_geodes(blueprint: &Blueprint, num_minutes: u32) -> u32 {
+    type Counts = [u32; 4];
+
+    fn best(
+        costs: &[Counts; 4],
+        minutes_left: u32,
+        robots: Counts,
+        resources: Counts,
+        cache: &mut HashMap<(u32, Counts, Counts), u32>,
+    ) -> u32 {
+        if minutes_left == 0 {
+            return resources[3];
+        }
+        if let Some(most) = cache.get(&(minutes_left, robots, resources)) {
+            return *most;
+        }
+
+        let mut most = 0;
+        for choice in (0..4).map(Some).chain([None]) {
+            let mut robots = robots;
+            let mut resources = resources;
+            if let Some(robot) = choice {
+                if (0..4).any(|resource| resources[resource] < costs[robot][resource]) {
+                    continue;
+                }
+                (0..4).for_each(|resource| resources[resource] -= costs[robot][resource]);
+            }
+            (0..4).for_each(|resource| resources[resource] += robots[resource]);
+            if let Some(robot) = choice {
+                robots[robot] += 1;
+            }
+            most = most.max(best(costs, minutes_left - 1, robots, resources, cache));
+        }
+        cache.insert((minutes_left, robots, resources), most);
+        most
+    }
+
+    // Indexed like ROBOT_TYPES and then RESOURCE_TYPES
+    let costs = ROBOT_TYPES.map(|robot| {
+        RESOURCE_TYPES.map(|resource| {
+            blueprint.robot_costs[robot]
+                .iter()
+                .filter(|requirement| requirement.resource == resource)
+                .map(|requirement| requirement.cost)
+                .sum()
+        })
+    });
+    best(
+        &costs,
+        num_minutes,
+        [1, 0, 0, 0],
+        [0; 4],
+        &mut HashMap::new(),
+    )
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn printed_blueprints_parse_back(id: u32, costs: [u32; 6]) {
+        let line = format!(
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+            id, costs[0], costs[1], costs[2], costs[3], costs[4], costs[5]
+        );
+        let blueprints = parse_blueprints(&line).unwrap();
+        let requirements = |robot: &str| -> Vec<(&str, u32)> {
+            blueprints[0].robot_costs[robot]
+                .iter()
+                .map(|requirement| (requirement.resource, requirement.cost))
+                .collect()
+        };
+
+        proptest::prop_assert_eq!(blueprints[0].id, id);
+        proptest::prop_assert_eq!(requirements("ore_robot"), vec![("ore", costs[0])]);
+        proptest::prop_assert_eq!(requirements("clay_robot"), vec![("ore", costs[1])]);
+        proptest::prop_assert_eq!(
+            requirements("obsidian_robot"),
+            vec![("ore", costs[2]), ("clay", costs[3])]
+        );
+        proptest::prop_assert_eq!(
+            requirements("geode_robot"),
+            vec![("ore", costs[4]), ("obsidian", costs[5])]
+        );
+    }
+
+    #[test]
+    fn parse_blueprints_never_panics(
+        input in "Blueprint [0-9]{0,3}:( Each [a-z]{0,8} robot costs [0-9]{0,11} [a-z]{0,8}\\.?){0,4}|\\PC{0,64}"
+    ) {
+        let _ = parse_blueprints(&input);
+    }
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #![proptest_config(proptest::prelude::ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn differential_max_geodes(
+        ore_costs in [1..5u32, 1..5, 1..5, 1..5],
+        clay_cost in 1..8u32,
+        obsidian_cost in 1..8u32,
+        num_minutes in 1..16u32,
+    ) {
+        let input_text = format!(
+            "Blueprint 1: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+            ore_costs[0], ore_costs[1], ore_costs[2], clay_cost, ore_costs[3], obsidian_cost
+        );
+        let blueprints = parse_blueprints(&input_text).unwrap();
+
+        proptest::prop_assert_eq!(
+            process_blueprint(&blueprints[0], num_minutes).unwrap(),
+            exhaustive_max_geodes(&blueprints[0], num_minutes),
+            "{}",
+            input_text
+        );
+    }
+}
+
+#[test]
+fn test_answers_too_large() {
+    let blueprint = "Each ore robot costs 1 ore. Each clay robot costs 1 ore. Each obsidian robot costs 1 ore and 1 clay. Each geode robot costs 1 ore and 1 obsidian.";
+    let input_text = (1..=6)
+        .map(|id| format!("Blueprint {}: {}", id, blueprint))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let blueprints = parse_blueprints(&input_text).unwrap();
+
+    // 55 geodes each, and 55 to the power of 6 doesn't fit in a u32
+    assert_eq!(process_blueprint(&blueprints[0], 16).unwrap(), 55);
+    assert_eq!(
+        part_two(&blueprints, 16, 6).unwrap_err().to_string(),
+        "no answer: the answer gets too large to count"
+    );
+}
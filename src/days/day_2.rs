@@ -0,0 +1,139 @@
+use crate::error::{Error, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::input::lines;
+use crate::solution::{Answer, Solution};
+
+/// A shape in rock paper scissors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+/// How a round should end, going by part two's reading of the guide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+/// One line of the strategy guide. The second column is read as `my_move` in part one and as
+/// `outcome` in part two.
+#[derive(Debug, Clone, Copy)]
+pub struct Round {
+    pub opponent_move: Move,
+    pub my_move: Move,
+    pub outcome: Outcome,
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Parsed = Vec<Round>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Round>, Error> {
+        Ok(parse_rounds(input)?)
+    }
+
+    fn part_one(&self, rounds: &Vec<Round>) -> Result<Answer, Error> {
+        Ok(part_one(rounds).into())
+    }
+
+    fn part_two(&self, rounds: &Vec<Round>) -> Result<Answer, Error> {
+        Ok(part_two(rounds).into())
+    }
+}
+
+/// `size` rounds.
+impl Generate for Day2 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| format!("{} {}", rng.letter(b'A'..=b'C'), rng.letter(b'X'..=b'Z')))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Reads the strategy guide, one round per line.
+pub fn parse_rounds(input_text: &str) -> Result<Vec<Round>, ParseError> {
+    lines(input_text)
+        .map(|line| {
+            let opponent_move = match line.get(..1) {
+                Some("A") => Move::Rock,
+                Some("B") => Move::Paper,
+                Some("C") => Move::Scissors,
+                _ => return Err(ParseError::at(input_text, line, "`A`, `B` or `C`")),
+            };
+            let second_column = &line[1..];
+            let Some(second_column) = second_column.strip_prefix(' ') else {
+                return Err(ParseError::at(input_text, second_column, "a space"));
+            };
+            let (my_move, outcome) = match second_column {
+                "X" => (Move::Rock, Outcome::Lose),
+                "Y" => (Move::Paper, Outcome::Draw),
+                "Z" => (Move::Scissors, Outcome::Win),
+                _ => return Err(ParseError::at(input_text, second_column, "`X`, `Y` or `Z`")),
+            };
+
+            Ok(Round {
+                opponent_move,
+                my_move,
+                outcome,
+            })
+        })
+        .collect()
+}
+
+/// My total score if the second column is the shape to play.
+pub fn part_one(rounds: &[Round]) -> i32 {
+    rounds
+        .iter()
+        .map(|round| calculate_my_score(round.my_move, round.opponent_move))
+        .sum::<i32>()
+}
+
+/// My total score if the second column is how the round has to end.
+pub fn part_two(rounds: &[Round]) -> i32 {
+    rounds
+        .iter()
+        .map(|round| {
+            let my_move = get_move_for_outcome(round.opponent_move, round.outcome);
+            calculate_my_score(my_move, round.opponent_move)
+        })
+        .sum::<i32>()
+}
+
+fn calculate_my_score(my_move: Move, opponent_move: Move) -> i32 {
+    let my_move_int = map_move_to_int(my_move);
+    let opponent_move_int = map_move_to_int(opponent_move);
+    let outcome = match (my_move_int % 3) - (opponent_move_int % 3) {
+        -1 | 2 => 0, // Loss
+        1 | -2 => 6, // Win
+        _ => 3,      // Draw
+    };
+
+    my_move_int + outcome
+}
+
+fn map_move_to_int(player_move: Move) -> i32 {
+    match player_move {
+        Move::Rock => 1,
+        Move::Paper => 2,
+        Move::Scissors => 3,
+    }
+}
+
+fn get_move_for_outcome(opponent_move: Move, outcome: Outcome) -> Move {
+    match (outcome, opponent_move) {
+        (Outcome::Draw, _) => opponent_move,
+        (Outcome::Lose, Move::Rock) => Move::Scissors,
+        (Outcome::Lose, Move::Paper) => Move::Rock,
+        (Outcome::Lose, Move::Scissors) => Move::Paper,
+        (Outcome::Win, Move::Rock) => Move::Paper,
+        (Outcome::Win, Move::Paper) => Move::Scissors,
+        (Outcome::Win, Move::Scissors) => Move::Rock,
+    }
+}
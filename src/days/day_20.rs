@@ -0,0 +1,387 @@
+use crate::error::{parse_number, Error, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::input::lines;
+use crate::params::{self, Param};
+use crate::solution::{Answer, Solution};
+struct Node {
+    value: i64,
+    next_index: usize,
+    prev_index: usize,
+}
+
+/// The encrypted file as a circular list that numbers can be moved around in. Each number
+/// remembers where it started, which is the order they are mixed in.
+pub struct LinkedList {
+    nodes: Vec<Node>,
+    head_index: usize,
+    tail_index: usize,
+    zero_index: usize,
+    length: usize,
+}
+
+impl LinkedList {
+    /// The list of `numbers`, each multiplied by `decryption_key`. There have to be at least two
+    /// numbers, as [`parse_numbers`] checks.
+    pub fn new(numbers: &[i64], decryption_key: i64) -> Self {
+        let mut zero_index = 0;
+
+        let mut nodes: Vec<Node> = numbers
+            .iter()
+            .enumerate()
+            .map(|(index, number)| {
+                let value = number * decryption_key;
+                if value == 0 {
+                    zero_index = index;
+                }
+
+                Node {
+                    value,
+                    next_index: index + 1,
+                    prev_index: 0.max(index as i64 - 1) as usize,
+                }
+            })
+            .collect();
+
+        let length = nodes.len();
+        nodes[0].prev_index = length - 1;
+        nodes[length - 1].next_index = 0;
+
+        LinkedList {
+            nodes,
+            head_index: 0,
+            tail_index: length - 1,
+            zero_index,
+            length,
+        }
+    }
+
+    /// Moves every number once, in the order they started in.
+    pub fn mix(&mut self) {
+        for i in 0..self.length {
+            self.shift_number(i);
+        }
+    }
+
+    fn shift_number(&mut self, base_index: usize) {
+        // base_index is the index we are shifting FROM THE ORIGINAL ORDER
+        // not the current state of the linkedlist
+
+        let current_value = self.nodes[base_index].value;
+        let move_amount = self.get_move_amount(current_value);
+
+        let node_to_move = base_index;
+
+        if move_amount == 0 {
+            return;
+        }
+
+        // Detach the current node
+        let next_node = self.nodes[node_to_move].next_index;
+        let prev_node = self.nodes[node_to_move].prev_index;
+
+        self.nodes[prev_node].next_index = next_node;
+        self.nodes[next_node].prev_index = prev_node;
+
+        if base_index == self.head_index {
+            self.head_index = next_node;
+        } else if base_index == self.tail_index {
+            self.tail_index = prev_node;
+        }
+
+        // Move to where we are reinserting the node
+        let mut current_index = node_to_move;
+
+        if move_amount >= 0 {
+            for _ in 0..(move_amount.unsigned_abs() as usize + 1) {
+                current_index = self.nodes[current_index].next_index;
+            }
+        } else {
+            for _ in 0..(move_amount.unsigned_abs() as usize) {
+                current_index = self.nodes[current_index].prev_index;
+            }
+        }
+
+        // Re-Insert node in list
+        let prev_node = self.nodes[current_index].prev_index;
+        self.nodes[current_index].prev_index = node_to_move;
+        self.nodes[prev_node].next_index = node_to_move;
+
+        self.nodes[node_to_move].next_index = current_index;
+        self.nodes[node_to_move].prev_index = prev_node;
+    }
+
+    fn get_move_amount(&self, current_value: i64) -> i64 {
+        current_value
+            - (current_value / (self.nodes.len() as i64 - 1)) * (self.nodes.len() as i64 - 1)
+    }
+
+    /// The number `pos` places after the 0, wrapping round the list.
+    pub fn get_value_at_position_from_zero(&self, pos: usize) -> i64 {
+        let mut current_node = self.zero_index;
+        for _ in 0..pos {
+            current_node = self.nodes[current_node].next_index;
+        }
+
+        self.nodes[current_node].value
+    }
+
+    #[allow(dead_code)]
+    fn print_list(&mut self) {
+        let mut current_index = self.head_index;
+        for _ in 0..self.length {
+            print!("{}, ", self.nodes[current_index].value);
+            current_index = self.nodes[current_index].next_index;
+        }
+        println!("\n");
+    }
+}
+
+/// Reads the numbers, one per line. The list has to have at least two numbers (otherwise there
+/// is nowhere to move to) and a 0 for the grove coordinates to be counted from.
+pub fn parse_numbers(input_text: &str) -> Result<Vec<i64>, ParseError> {
+    let numbers = lines(input_text)
+        .map(|line| parse_number(input_text, line, "a whole number"))
+        .collect::<Result<Vec<i64>, _>>()?;
+
+    let end_of_input = &input_text[input_text.len()..];
+    if numbers.len() < 2 {
+        return Err(ParseError::at(
+            input_text,
+            end_of_input,
+            "at least two numbers",
+        ));
+    }
+    if !numbers.contains(&0) {
+        return Err(ParseError::at(
+            input_text,
+            end_of_input,
+            "a 0 somewhere in the list",
+        ));
+    }
+    Ok(numbers)
+}
+
+/// The grove coordinates after mixing the numbers once.
+pub fn part_one(numbers: &[i64]) -> i64 {
+    let mut linked_list = LinkedList::new(numbers, 1);
+    linked_list.mix();
+
+    let pos_1000 = linked_list.get_value_at_position_from_zero(1000);
+    let pos_2000 = linked_list.get_value_at_position_from_zero(2000);
+    let pos_3000 = linked_list.get_value_at_position_from_zero(3000);
+
+    pos_1000 + pos_2000 + pos_3000
+}
+
+/// The grove coordinates after multiplying the numbers by `decryption_key` and mixing them
+/// `num_mix_rounds` times.
+pub fn part_two(numbers: &[i64], decryption_key: i64, num_mix_rounds: usize) -> Result<i64, Error> {
+    if numbers
+        .iter()
+        .any(|number| number.checked_mul(decryption_key).is_none())
+    {
+        return Err(Error::NoAnswer(
+            "the decryption key makes the numbers too large".to_string(),
+        ));
+    }
+
+    let mut linked_list = LinkedList::new(numbers, decryption_key);
+
+    for _ in 0..num_mix_rounds {
+        linked_list.mix();
+    }
+
+    let pos_1000 = linked_list.get_value_at_position_from_zero(1000);
+    let pos_2000 = linked_list.get_value_at_position_from_zero(2000);
+    let pos_3000 = linked_list.get_value_at_position_from_zero(3000);
+
+    Ok(pos_1000 + pos_2000 + pos_3000)
+}
+
+pub struct Day20 {
+    pub decryption_key: i64,
+    pub part_two_rounds: usize,
+}
+
+impl Default for Day20 {
+    fn default() -> Self {
+        Day20 {
+            decryption_key: 811589153,
+            part_two_rounds: 10,
+        }
+    }
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Parsed = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Result<Vec<i64>, Error> {
+        Ok(parse_numbers(input)?)
+    }
+
+    fn part_one(&self, numbers: &Vec<i64>) -> Result<Answer, Error> {
+        Ok(part_one(numbers).into())
+    }
+
+    fn part_two(&self, numbers: &Vec<i64>) -> Result<Answer, Error> {
+        Ok(part_two(numbers, self.decryption_key, self.part_two_rounds)?.into())
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new(
+                "decryption_key",
+                "What every number is multiplied by in part two",
+                self.decryption_key,
+            ),
+            Param::new(
+                "part_two_rounds",
+                "How many times the numbers are mixed in part two",
+                self.part_two_rounds as i64,
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: i64) -> Result<(), String> {
+        match name {
+            "decryption_key" => self.decryption_key = value,
+            "part_two_rounds" => self.part_two_rounds = params::non_negative(value)?,
+            _ => return Err(format!("there is no parameter called `{}`", name)),
+        }
+        Ok(())
+    }
+}
+
+/// `size` numbers (at least two), exactly one of them 0.
+impl Generate for Day20 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut numbers: Vec<i64> = (0..size.max(2))
+            .map(|_| rng.range(1..=10000) * if rng.chance(1, 2) { 1 } else { -1 })
+            .collect();
+        numbers[rng.index(size.max(2))] = 0;
+        numbers
+            .iter()
+            .map(i64::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[test]
+fn test_part_one() {
+    let input_text = "1
+2
+-3
+3
+-2
+0
+4";
+    assert_eq!(part_one(&parse_numbers(input_text).unwrap()), 3);
+}
+
+#[test]
+fn test_part_two() {
+    let input_text = "1
+2
+-3
+3
+-2
+0
+4";
+    assert_eq!(
+        part_two(&parse_numbers(input_text).unwrap(), 811589153, 10).unwrap(),
+        1623178306
+    );
+}
+
+#[test]
+fn test_cyclical_optimisation() {
+    let input_text = "1
+2
+-3
+3
+-2
+0
+4";
+
+    let linked_list = LinkedList::new(&parse_numbers(input_text).unwrap(), 1);
+
+    assert_eq!(linked_list.get_move_amount(1), 1);
+    assert_eq!(linked_list.get_move_amount(-1), -1);
+    assert_eq!(linked_list.get_move_amount(3), 3);
+    assert_eq!(linked_list.get_move_amount(-3), -3);
+    assert_eq!(linked_list.get_move_amount(10), 4);
+    assert_eq!(linked_list.get_move_amount(-10), -4);
+    assert_eq!(linked_list.get_move_amount(6_000_000), 0);
+}
+
+/// Mixes by removing each number from a `Vec` and inserting it again where it lands, to compare
+/// against the linked list skipping whole laps with [`LinkedList::get_move_amount`].
+#[cfg(test)]
+fn mix_by_hand(numbers: &[i64], decryption_key: i64, num_mix_rounds: usize) -> i64 {
+    let mut list: Vec<(usize, i64)> = numbers
+        .iter()
+        .map(|number| number * decryption_key)
+        .enumerate()
+        .collect();
+    let length = list.len() as i64;
+    for _ in 0..num_mix_rounds {
+        for original_index in 0..list.len() {
+            let position = list
+                .iter()
+                .position(|(index, _)| *index == original_index)
+                .unwrap();
+            let (index, value) = list.remove(position);
+            let new_position = (position as i64 + value).rem_euclid(length - 1);
+            list.insert(new_position as usize, (index, value));
+        }
+    }
+
+    let zero = list.iter().position(|(_, value)| *value == 0).unwrap();
+    [1000, 2000, 3000]
+        .iter()
+        .map(|offset| list[(zero + offset) % list.len()].1)
+        .sum()
+}
+
+/// A list of numbers with exactly one 0, like the real puzzle inputs, as input text.
+#[cfg(test)]
+fn numbers_strategy() -> impl proptest::strategy::Strategy<Value = String> {
+    use proptest::strategy::Strategy;
+
+    let non_zero = (-60..60i64).prop_filter("the only 0 is added separately", |n| *n != 0);
+    proptest::collection::vec(non_zero, 1..20)
+        .prop_flat_map(|numbers| {
+            let zero_at = 0..=numbers.len();
+            (proptest::strategy::Just(numbers), zero_at)
+        })
+        .prop_map(|(mut numbers, zero_at)| {
+            numbers.insert(zero_at, 0);
+            numbers
+                .iter()
+                .map(i64::to_string)
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn differential_part_one(input_text in numbers_strategy()) {
+        let numbers = parse_numbers(&input_text).unwrap();
+
+        proptest::prop_assert_eq!(part_one(&numbers), mix_by_hand(&numbers, 1, 1));
+    }
+
+    #[test]
+    fn differential_part_two(input_text in numbers_strategy(), num_mix_rounds in 1..4usize) {
+        let numbers = parse_numbers(&input_text).unwrap();
+
+        proptest::prop_assert_eq!(
+            part_two(&numbers, 811589153, num_mix_rounds).unwrap(),
+            mix_by_hand(&numbers, 811589153, num_mix_rounds)
+        );
+    }
+}
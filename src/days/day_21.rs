@@ -0,0 +1,581 @@
+use crate::error::{first_problem, Error, NomResult, ParseError};
+use crate::generate::{unique_name, Generate, Rng};
+use crate::input::lines;
+use crate::solution::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, i64 as number, one_of};
+use nom::combinator::all_consuming;
+use nom::error::context;
+
+/// The operations a monkey can do with the numbers two other monkeys yell.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Operation {
+    Addition,
+    Subtraction,
+    Multiplication,
+    Division,
+}
+
+/// What a monkey yells: either a number or the result of an operation on two other monkeys.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MonkeyCalculation {
+    Value(i64),
+    Calculation {
+        left: String,
+        operation: Operation,
+        right: String,
+    },
+}
+
+/// Parses one line into the monkey's name, its job, and the names of the monkeys it waits for
+/// (still pointing into the input so they can be reported if they don't exist).
+fn parse_monkey_calculation(input: &str) -> NomResult<'_, (&str, MonkeyCalculation, Vec<&str>)> {
+    let (input, monkey_name) = context("a monkey name", alpha1)(input)?;
+
+    let (input, _) = context("`: `", tag(": "))(input)?;
+
+    if let Ok((input, number)) = number::<_, nom::error::VerboseError<&str>>(input) {
+        return Ok((
+            input,
+            (monkey_name, MonkeyCalculation::Value(number), vec![]),
+        ));
+    }
+
+    let (input, left_side_monkey) = context("a number or a monkey name", alpha1)(input)?;
+
+    let (input, _) = context("` `", tag(" "))(input)?;
+
+    let (input, operator) = context("`+`, `-`, `*` or `/`", one_of("+-*/"))(input)?;
+
+    let operation = match operator {
+        '+' => Operation::Addition,
+        '-' => Operation::Subtraction,
+        '/' => Operation::Division,
+        _ => Operation::Multiplication,
+    };
+
+    let (input, _) = context("` `", tag(" "))(input)?;
+
+    let (input, right_side_monkey) = context("a monkey name", alpha1)(input)?;
+
+    let monkey_calc = MonkeyCalculation::Calculation {
+        left: left_side_monkey.to_string(),
+        operation,
+        right: right_side_monkey.to_string(),
+    };
+
+    Ok((
+        input,
+        (
+            monkey_name,
+            monkey_calc,
+            vec![left_side_monkey, right_side_monkey],
+        ),
+    ))
+}
+
+/// Reads each monkey's job, keyed by the monkey's name.
+pub fn parse_monkey_calculations(
+    input_text: &str,
+) -> Result<HashMap<String, MonkeyCalculation>, ParseError> {
+    let mut problems = vec![];
+    let monkey_calculations = read_monkey_calculations(input_text, &mut problems);
+    first_problem(monkey_calculations, problems)
+}
+
+/// Every monkey has to be defined once, `root` has to exist and every monkey that is waited on
+/// has to be defined somewhere in the input. Anything that isn't is added to `problems`.
+fn read_monkey_calculations(
+    input_text: &str,
+    problems: &mut Vec<ParseError>,
+) -> HashMap<String, MonkeyCalculation> {
+    let mut monkey_calculations: HashMap<String, MonkeyCalculation> = HashMap::new();
+    let mut waits_for_by_name: Vec<(&str, Vec<&str>)> = vec![];
+
+    for line in lines(input_text) {
+        let (name, calculation, waits_for) = match all_consuming(parse_monkey_calculation)(line) {
+            Ok((_, parsed)) => parsed,
+            Err(error) => {
+                problems.push(ParseError::from_nom(input_text, error));
+                continue;
+            }
+        };
+        if monkey_calculations.contains_key(name) {
+            problems.push(ParseError::at(
+                input_text,
+                name,
+                format!("a monkey other than `{}`, which is already defined", name),
+            ));
+            continue;
+        }
+        monkey_calculations.insert(name.to_string(), calculation);
+        waits_for_by_name.push((name, waits_for));
+    }
+
+    let num_problems = problems.len();
+    problems.extend(
+        waits_for_by_name
+            .iter()
+            .flat_map(|(_, waits_for)| waits_for)
+            .filter(|name| !monkey_calculations.contains_key(**name))
+            .map(|missing| {
+                ParseError::at(
+                    input_text,
+                    missing,
+                    "the name of a monkey defined in the input",
+                )
+            }),
+    );
+    let all_defined = problems.len() == num_problems;
+    if !monkey_calculations.contains_key("root") {
+        problems.push(ParseError::at(
+            input_text,
+            &input_text[input_text.len()..],
+            "a monkey called `root`",
+        ));
+    }
+    // Following the monkeys round needs every one they wait for to exist
+    if all_defined {
+        if let Some(name) = find_cycle(&waits_for_by_name) {
+            problems.push(ParseError::at(
+                input_text,
+                name,
+                format!("`{}` not to end up waiting for its own number", name),
+            ));
+        }
+    }
+    monkey_calculations
+}
+
+/// Finds a monkey that (through other monkeys) waits for itself, which would make the
+/// recursive calculations never finish. `waits_for_by_name` has to mention every monkey.
+fn find_cycle<'a>(waits_for_by_name: &[(&'a str, Vec<&'a str>)]) -> Option<&'a str> {
+    #[derive(PartialEq)]
+    enum Visit {
+        InProgress,
+        Done,
+    }
+
+    let waits_for: HashMap<&str, &[&str]> = waits_for_by_name
+        .iter()
+        .map(|(name, waits_for)| (*name, &waits_for[..]))
+        .collect();
+    let mut visits: HashMap<&str, Visit> = HashMap::new();
+
+    for (start, _) in waits_for_by_name {
+        if visits.contains_key(start) {
+            continue;
+        }
+        visits.insert(start, Visit::InProgress);
+        let mut stack = vec![(*start, 0)];
+
+        while let Some((name, next)) = stack.last_mut() {
+            let Some(other) = waits_for[*name].get(*next) else {
+                visits.insert(name, Visit::Done);
+                stack.pop();
+                continue;
+            };
+            *next += 1;
+            match visits.get(other) {
+                Some(Visit::InProgress) => return Some(other),
+                Some(Visit::Done) => {}
+                None => {
+                    visits.insert(other, Visit::InProgress);
+                    stack.push((other, 0));
+                }
+            }
+        }
+    }
+    None
+}
+
+fn overflow() -> Error {
+    Error::NoAnswer("the numbers get too large to yell".to_string())
+}
+
+fn perform_monkey_calculation(
+    current_monkey: &str,
+    monkey_map: &HashMap<String, MonkeyCalculation>,
+) -> Result<i64, Error> {
+    let (left, operation, right) = match &monkey_map[current_monkey] {
+        MonkeyCalculation::Value(value) => return Ok(*value),
+        MonkeyCalculation::Calculation {
+            left,
+            operation,
+            right,
+        } => (left, operation, right),
+    };
+
+    let left_num = perform_monkey_calculation(left, monkey_map)?;
+    let right_num = perform_monkey_calculation(right, monkey_map)?;
+
+    match operation {
+        Operation::Addition => left_num.checked_add(right_num).ok_or_else(overflow),
+        Operation::Subtraction => left_num.checked_sub(right_num).ok_or_else(overflow),
+        Operation::Multiplication => left_num.checked_mul(right_num).ok_or_else(overflow),
+        Operation::Division if right_num == 0 => Err(Error::NoAnswer(format!(
+            "`{}` divides by zero",
+            current_monkey
+        ))),
+        Operation::Division => left_num.checked_div(right_num).ok_or_else(overflow),
+    }
+}
+
+fn contains_human(current_monkey: &str, monkey_map: &HashMap<String, MonkeyCalculation>) -> bool {
+    if current_monkey == "humn" {
+        return true;
+    }
+
+    match &monkey_map[current_monkey] {
+        MonkeyCalculation::Value(_) => false,
+        MonkeyCalculation::Calculation { left, right, .. } => {
+            contains_human(left, monkey_map) || contains_human(right, monkey_map)
+        }
+    }
+}
+
+fn find_num_to_yell(
+    current_monkey: &str,
+    desired_num: i64,
+    monkey_map: &HashMap<String, MonkeyCalculation>,
+) -> Result<i64, Error> {
+    if current_monkey == "humn" {
+        return Ok(desired_num);
+    }
+
+    let MonkeyCalculation::Calculation {
+        left: left_monkey,
+        operation,
+        right: right_monkey,
+    } = &monkey_map[current_monkey]
+    else {
+        return Err(Error::NoAnswer(format!(
+            "`{}` yells a fixed number so `humn` can't change it",
+            current_monkey
+        )));
+    };
+
+    let left_contains_human = contains_human(left_monkey, monkey_map);
+    let right_contains_human = contains_human(right_monkey, monkey_map);
+
+    let mut left_val: Option<i64> = None;
+    let mut right_val: Option<i64> = None;
+    if !left_contains_human {
+        left_val = Some(perform_monkey_calculation(left_monkey, monkey_map)?);
+    }
+    if !right_contains_human {
+        right_val = Some(perform_monkey_calculation(right_monkey, monkey_map)?);
+    }
+
+    // Undo the operation to figure out the value needed to receive the desired_num
+    match (left_val, operation, right_val) {
+        (Some(left_val), Operation::Addition, None) => {
+            find_num_to_yell(right_monkey, desired_num - left_val, monkey_map)
+        }
+        (None, Operation::Addition, Some(right_val)) => {
+            find_num_to_yell(left_monkey, desired_num - right_val, monkey_map)
+        }
+        (Some(left_val), Operation::Subtraction, None) => {
+            find_num_to_yell(right_monkey, left_val - desired_num, monkey_map)
+        }
+        (None, Operation::Subtraction, Some(right_val)) => {
+            find_num_to_yell(left_monkey, desired_num + right_val, monkey_map)
+        }
+        (Some(0), Operation::Multiplication, None)
+        | (None, Operation::Multiplication, Some(0))
+        | (None, Operation::Division, Some(0)) => Err(Error::NoAnswer(format!(
+            "`{}` multiplies or divides by zero so `humn` can't change it",
+            current_monkey
+        ))),
+        (Some(_), Operation::Division, None) if desired_num == 0 => Err(Error::NoAnswer(format!(
+            "`{}` would need `humn`'s side to divide into zero",
+            current_monkey
+        ))),
+        (Some(left_val), Operation::Multiplication, None) => {
+            find_num_to_yell(right_monkey, desired_num / left_val, monkey_map)
+        }
+        (None, Operation::Multiplication, Some(right_val)) => {
+            find_num_to_yell(left_monkey, desired_num / right_val, monkey_map)
+        }
+        (Some(left_val), Operation::Division, None) => {
+            find_num_to_yell(right_monkey, left_val / desired_num, monkey_map)
+        }
+        (None, Operation::Division, Some(right_val)) => {
+            find_num_to_yell(left_monkey, desired_num * right_val, monkey_map)
+        }
+        _ => Err(Error::NoAnswer(format!(
+            "`humn` appears on both sides of `{}`",
+            current_monkey
+        ))),
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Parsed = HashMap<String, MonkeyCalculation>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, Error> {
+        Ok(parse_monkey_calculations(input)?)
+    }
+
+    fn part_one(&self, monkey_calculations: &Self::Parsed) -> Result<Answer, Error> {
+        Ok(part_one(monkey_calculations)?.into())
+    }
+
+    fn part_two(&self, monkey_calculations: &Self::Parsed) -> Result<Answer, Error> {
+        Ok(part_two(monkey_calculations)?.into())
+    }
+
+    fn validate(&self, input: &str) -> Vec<ParseError> {
+        let mut problems = vec![];
+        read_monkey_calculations(input, &mut problems);
+        problems
+    }
+}
+
+/// About `size` monkeys yelling a random sum. Divisions always come out exactly, and `humn` is
+/// only ever added, subtracted or multiplied by a number other than 0 on its way up to `root`, so
+/// both parts have an answer.
+impl Generate for Day21 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        // A tree with this many numbers yelled has twice as many monkeys, give or take one
+        let num_leaves = size.max(3).div_ceil(2);
+        let human = rng.index(num_leaves);
+        let mut tree = GeneratedMonkeys {
+            rng,
+            taken: HashSet::from(["root".to_string(), "humn".to_string()]),
+            lines: vec![],
+            leaves_before_human: Some(human),
+        };
+        let split = 1 + tree.rng.index(num_leaves - 1);
+        let (left, _, _) = tree.monkey(split);
+        let (right, _, _) = tree.monkey(num_leaves - split);
+        tree.lines.push(format!("root: {} + {}", left, right));
+
+        let GeneratedMonkeys { rng, mut lines, .. } = tree;
+        rng.shuffle(&mut lines);
+        lines.join("\n")
+    }
+}
+
+struct GeneratedMonkeys<'a> {
+    rng: &'a mut Rng,
+    taken: HashSet<String>,
+    lines: Vec<String>,
+    /// Counts down as numbers are handed out, and the one handed out at 0 goes to `humn`.
+    leaves_before_human: Option<usize>,
+}
+
+impl GeneratedMonkeys<'_> {
+    /// Adds a monkey whose number depends on `leaves` numbers being yelled, returning its name,
+    /// the number it yells and whether `humn` is one of the monkeys it waits for.
+    fn monkey(&mut self, leaves: usize) -> (String, i64, bool) {
+        if leaves == 1 {
+            let is_human = self.leaves_before_human == Some(0);
+            self.leaves_before_human = self
+                .leaves_before_human
+                .and_then(|left| left.checked_sub(1));
+            let name = if is_human {
+                "humn".to_string()
+            } else {
+                unique_name(self.rng, &mut self.taken)
+            };
+            let value = self.rng.range(1..=20);
+            self.lines.push(format!("{}: {}", name, value));
+            return (name, value, is_human);
+        }
+
+        let split = 1 + self.rng.index(leaves - 1);
+        let (left, left_value, left_human) = self.monkey(split);
+        let (right, right_value, right_human) = self.monkey(leaves - split);
+        let has_human = left_human || right_human;
+        let (operation, value) = match self.rng.index(4) {
+            1 => ('-', left_value - right_value),
+            2 if left_value != 0
+                && right_value != 0
+                && left_value
+                    .checked_mul(right_value)
+                    .is_some_and(|value| value.abs() <= 1_000_000_000) =>
+            {
+                ('*', left_value * right_value)
+            }
+            3 if !has_human && right_value != 0 && left_value % right_value == 0 => {
+                ('/', left_value / right_value)
+            }
+            _ => ('+', left_value + right_value),
+        };
+
+        let name = unique_name(self.rng, &mut self.taken);
+        self.lines
+            .push(format!("{}: {} {} {}", name, left, operation, right));
+        (name, value, has_human)
+    }
+}
+
+/// The number the `root` monkey yells.
+pub fn part_one(monkey_calculations: &HashMap<String, MonkeyCalculation>) -> Result<i64, Error> {
+    perform_monkey_calculation("root", monkey_calculations)
+}
+
+/// The number `humn` has to yell for the two monkeys `root` waits for to yell the same number.
+pub fn part_two(monkey_calculations: &HashMap<String, MonkeyCalculation>) -> Result<i64, Error> {
+    let MonkeyCalculation::Calculation { left, right, .. } = &monkey_calculations["root"] else {
+        return Err(Error::NoAnswer(
+            "`root` has to compare two other monkeys".to_string(),
+        ));
+    };
+
+    match (
+        contains_human(left, monkey_calculations),
+        contains_human(right, monkey_calculations),
+    ) {
+        (false, true) => {
+            let desired_result = perform_monkey_calculation(left, monkey_calculations)?;
+            find_num_to_yell(right, desired_result, monkey_calculations)
+        }
+        (true, false) => {
+            let desired_result = perform_monkey_calculation(right, monkey_calculations)?;
+            find_num_to_yell(left, desired_result, monkey_calculations)
+        }
+        _ => Err(Error::NoAnswer(
+            "`humn` has to be on exactly one side of `root`".to_string(),
+        )),
+    }
+}
+
+#[test]
+fn test_part_one() {
+    let input_text = "root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32";
+
+    assert_eq!(
+        part_one(&parse_monkey_calculations(input_text).unwrap()).unwrap(),
+        152
+    );
+}
+
+#[test]
+fn test_part_two() {
+    let input_text = "root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32";
+
+    assert_eq!(
+        part_two(&parse_monkey_calculations(input_text).unwrap()).unwrap(),
+        301
+    );
+}
+
+#[test]
+fn test_monkeys_waiting_for_themselves_are_an_error() {
+    let input_text = "root: aaaa + bbbb
+aaaa: 5
+bbbb: cccc * aaaa
+cccc: bbbb - aaaa";
+
+    assert_eq!(
+        parse_monkey_calculations(input_text).unwrap_err(),
+        ParseError::new(4, 7, "`bbbb` not to end up waiting for its own number")
+    );
+}
+
+#[test]
+fn test_validate_reports_every_problem() {
+    let input_text = "root: aaaa + zzzz
+aaaa: 5
+aaaa: 6
+bbbb: 7 + 2";
+
+    assert_eq!(
+        Day21.validate(input_text),
+        [
+            ParseError::new(3, 1, "a monkey other than `aaaa`, which is already defined"),
+            ParseError::new(4, 8, "end of file"),
+            ParseError::new(1, 14, "the name of a monkey defined in the input"),
+        ]
+    );
+}
+
+#[cfg(test)]
+fn monkey_line_strategy() -> impl proptest::strategy::Strategy<Value = (String, MonkeyCalculation)>
+{
+    use proptest::prelude::*;
+
+    let operation = prop_oneof![
+        Just(Operation::Addition),
+        Just(Operation::Subtraction),
+        Just(Operation::Multiplication),
+        Just(Operation::Division),
+    ];
+    let calculation = prop_oneof![
+        any::<i64>().prop_map(MonkeyCalculation::Value),
+        ("[a-z]{4}", operation, "[a-z]{4}").prop_map(|(left, operation, right)| {
+            MonkeyCalculation::Calculation {
+                left,
+                operation,
+                right,
+            }
+        }),
+    ];
+    ("[a-z]{4}", calculation)
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn printed_monkeys_parse_back((name, calculation) in monkey_line_strategy()) {
+        let line = match &calculation {
+            MonkeyCalculation::Value(value) => format!("{}: {}", name, value),
+            MonkeyCalculation::Calculation { left, operation, right } => {
+                let operator = match operation {
+                    Operation::Addition => '+',
+                    Operation::Subtraction => '-',
+                    Operation::Multiplication => '*',
+                    Operation::Division => '/',
+                };
+                format!("{}: {} {} {}", name, left, operator, right)
+            }
+        };
+        let (rest, (parsed_name, parsed, _)) = parse_monkey_calculation(&line).unwrap();
+
+        proptest::prop_assert_eq!(rest, "");
+        proptest::prop_assert_eq!(parsed_name, name);
+        proptest::prop_assert_eq!(parsed, calculation);
+    }
+
+    #[test]
+    fn parse_monkey_calculations_never_panics(
+        input in "(root|humn|[a-z]{1,2})(: ([0-9-]{1,3}|[a-z]{1,2} [-+*/] [a-z]{1,2}))?(\n[a-z :0-9+*/-]{0,16}){0,8}|\\PC{0,64}"
+    ) {
+        let _ = parse_monkey_calculations(&input);
+    }
+}
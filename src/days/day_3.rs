@@ -0,0 +1,205 @@
+use crate::error::{Error, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::input::lines;
+use crate::solution::{Answer, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Parsed = Vec<Rucksack>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Rucksack>, Error> {
+        Ok(parse_rucksacks(input)?)
+    }
+
+    fn part_one(&self, rucksacks: &Vec<Rucksack>) -> Result<Answer, Error> {
+        Ok(part_one(rucksacks).into())
+    }
+
+    fn part_two(&self, rucksacks: &Vec<Rucksack>) -> Result<Answer, Error> {
+        Ok(part_two(rucksacks).into())
+    }
+}
+
+/// `size` rucksacks, rounded up to a whole number of groups of three.
+impl Generate for Day3 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+        let mut rucksacks = vec![];
+        for _ in 0..size.div_ceil(3).max(1) {
+            let badge = *rng.choose(&items);
+            // Every other item is kept out of one of the three rucksacks, so the badge is the
+            // only item they all have
+            let left_out_of: Vec<usize> = items.iter().map(|_| rng.index(3)).collect();
+            for elf in 0..3 {
+                let allowed: Vec<char> = items
+                    .iter()
+                    .zip(&left_out_of)
+                    .filter(|(item, left_out_of)| **left_out_of != elf && **item != badge)
+                    .map(|(item, _)| *item)
+                    .collect();
+                let shared = if rng.chance(1, 4) {
+                    badge
+                } else {
+                    *rng.choose(&allowed)
+                };
+
+                // Everything but the shared item goes in only one of the compartments
+                let mut compartments = [vec![shared], vec![shared]];
+                if badge != shared {
+                    compartments[rng.index(2)].push(badge);
+                }
+                let mut pools = [vec![], vec![]];
+                for item in allowed.into_iter().filter(|item| *item != shared) {
+                    pools[rng.index(2)].push(item);
+                }
+                let len = rng.range(2..=16) as usize;
+                for (compartment, pool) in compartments.iter_mut().zip(&pools) {
+                    while compartment.len() < len {
+                        compartment.push(if pool.is_empty() {
+                            shared
+                        } else {
+                            *rng.choose(pool)
+                        });
+                    }
+                    rng.shuffle(compartment);
+                }
+                rucksacks.push(compartments.concat().into_iter().collect::<String>());
+            }
+        }
+        rucksacks.join("\n")
+    }
+}
+
+/// One elf's rucksack, with the same number of items in each of its two compartments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack {
+    items: String,
+    shared_item: char,
+}
+
+impl Rucksack {
+    /// The rucksack holding `items`, or `None` unless they are all letters that split evenly
+    /// into two compartments with an item in both.
+    pub fn new(items: &str) -> Option<Self> {
+        if !items.len().is_multiple_of(2) || !items.chars().all(|c| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        let (first_sack, second_sack) = items.split_at(items.len() / 2);
+        Some(Rucksack {
+            items: items.to_string(),
+            shared_item: get_shared_char(first_sack, second_sack)?,
+        })
+    }
+
+    /// Every item in the rucksack, first compartment first.
+    pub fn items(&self) -> &str {
+        &self.items
+    }
+
+    /// The item that is in both compartments.
+    pub fn shared_item(&self) -> char {
+        self.shared_item
+    }
+}
+
+/// Reads one rucksack per line, checking each only holds letters, splits evenly into two
+/// compartments with an item in both, and that every group of three shares a badge.
+pub fn parse_rucksacks(input_text: &str) -> Result<Vec<Rucksack>, ParseError> {
+    let mut rucksacks = vec![];
+    for line in lines(input_text) {
+        if let Some(offset) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+            return Err(ParseError::at(
+                input_text,
+                &line[offset..],
+                "an item from a-z or A-Z",
+            ));
+        }
+        if line.len() % 2 != 0 {
+            return Err(ParseError::at(
+                input_text,
+                &line[line.len()..],
+                "an even number of items",
+            ));
+        }
+        rucksacks.push(
+            Rucksack::new(line)
+                .ok_or_else(|| ParseError::at(input_text, line, "an item in both compartments"))?,
+        );
+    }
+
+    let lines: Vec<&str> = lines(input_text).collect();
+    for group in lines.chunks(3) {
+        let [first, second, third] = group else {
+            let end = group[group.len() - 1];
+            return Err(ParseError::at(
+                input_text,
+                &end[end.len()..],
+                "groups of three rucksacks",
+            ));
+        };
+        if get_shared_char_three(first, second, third).is_none() {
+            return Err(ParseError::at(
+                input_text,
+                first,
+                "a badge shared by the group of three",
+            ));
+        }
+    }
+    Ok(rucksacks)
+}
+
+/// The priorities of the item in both compartments of each rucksack, added together.
+pub fn part_one(rucksacks: &[Rucksack]) -> u32 {
+    rucksacks
+        .iter()
+        .map(|rucksack| calculate_priority(rucksack.shared_item))
+        .sum()
+}
+
+/// The priorities of the badge shared by each group of three rucksacks, added together. A group
+/// that is missing a rucksack or has no badge adds nothing, which [`parse_rucksacks`] never lets
+/// through.
+pub fn part_two(rucksacks: &[Rucksack]) -> u32 {
+    rucksacks
+        .chunks(3)
+        .filter_map(|group| match group {
+            [first, second, third] => {
+                get_shared_char_three(&first.items, &second.items, &third.items)
+            }
+            _ => None,
+        })
+        .map(calculate_priority)
+        .sum()
+}
+
+fn calculate_priority(item: char) -> u32 {
+    let ascii_val: u32 = item as u32;
+    if ascii_val >= 97 {
+        ascii_val - 96
+    } else {
+        ascii_val - (64 - 26)
+    }
+}
+
+fn get_shared_char(first: &str, second: &str) -> Option<char> {
+    first.chars().find(|char1| second.contains(*char1))
+}
+
+fn get_shared_char_three(first: &str, second: &str, third: &str) -> Option<char> {
+    first
+        .chars()
+        .find(|char1| second.contains(*char1) && third.contains(*char1))
+}
+
+#[test]
+fn test_new_rucksack() {
+    let rucksack = Rucksack::new("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap();
+
+    assert_eq!(rucksack.shared_item(), 'p');
+    assert_eq!(rucksack.items(), "vJrwpWtwJgWrhcsFMMfFFhFp");
+    assert_eq!(Rucksack::new("abc"), None);
+    assert_eq!(Rucksack::new("ab"), None);
+    assert_eq!(Rucksack::new("a1a1"), None);
+}
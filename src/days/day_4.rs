@@ -0,0 +1,119 @@
+use crate::error::{first_problem, parse_number, Error, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::input::lines;
+use crate::solution::{Answer, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Parsed = Vec<[u32; 4]>;
+
+    fn parse(&self, input: &str) -> Result<Vec<[u32; 4]>, Error> {
+        Ok(parse_section_ids(input)?)
+    }
+
+    fn part_one(&self, section_ids: &Vec<[u32; 4]>) -> Result<Answer, Error> {
+        Ok(part_one(section_ids).into())
+    }
+
+    fn part_two(&self, section_ids: &Vec<[u32; 4]>) -> Result<Answer, Error> {
+        Ok(part_two(section_ids).into())
+    }
+
+    fn validate(&self, input: &str) -> Vec<ParseError> {
+        let mut problems = vec![];
+        read_section_ids(input, &mut problems);
+        problems
+    }
+}
+
+/// `size` pairs of elves.
+impl Generate for Day4 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut range = || {
+            let (a, b) = (rng.range(1..=99), rng.range(1..=99));
+            format!("{}-{}", a.min(b), a.max(b))
+        };
+        (0..size.max(1))
+            .map(|_| format!("{},{}", range(), range()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Reads each pair of ranges like `2-4,6-8` as `[2, 4, 6, 8]`.
+pub fn parse_section_ids(input_text: &str) -> Result<Vec<[u32; 4]>, ParseError> {
+    let mut problems = vec![];
+    let section_ids = read_section_ids(input_text, &mut problems);
+    first_problem(section_ids, problems)
+}
+
+/// Each line is a pair of ranges like `2-4,6-8`, flattened into `[2, 4, 6, 8]`. Lines with a
+/// problem are left out and the problem added to `problems`.
+fn read_section_ids(input_text: &str, problems: &mut Vec<ParseError>) -> Vec<[u32; 4]> {
+    let parse_range = |range: &str| {
+        let (start, end) = range
+            .split_once('-')
+            .ok_or_else(|| ParseError::at(input_text, &range[range.len()..], "`-`"))?;
+        Ok::<_, ParseError>((
+            parse_number(input_text, start, "a section id")?,
+            parse_number(input_text, end, "a section id")?,
+        ))
+    };
+    let parse_line = |line: &str| {
+        let (first, second) = line
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(input_text, &line[line.len()..], "`,`"))?;
+        let (a, b) = parse_range(first)?;
+        let (c, d) = parse_range(second)?;
+        Ok([a, b, c, d])
+    };
+
+    lines(input_text)
+        .filter_map(|line| parse_line(line).map_err(|error| problems.push(error)).ok())
+        .collect()
+}
+
+/// How many pairs have one range entirely inside the other.
+pub fn part_one(section_ids: &[[u32; 4]]) -> u32 {
+    let mut sum: u32 = 0;
+    for ids in section_ids {
+        sum += ranges_contain_each_other(ids[0], ids[1], ids[2], ids[3]) as u32;
+    }
+    sum
+}
+
+/// How many pairs have ranges that overlap at all.
+pub fn part_two(section_ids: &[[u32; 4]]) -> u32 {
+    let mut sum: u32 = 0;
+    for ids in section_ids {
+        sum += ranges_overlap(ids[0], ids[1], ids[2], ids[3]) as u32;
+    }
+    sum
+}
+
+fn ranges_contain_each_other(a: u32, b: u32, c: u32, d: u32) -> bool {
+    if a < c && d > b {
+        false
+    } else {
+        !(c < a && b > d)
+    }
+}
+
+fn ranges_overlap(a: u32, b: u32, c: u32, d: u32) -> bool {
+    if a <= c && b >= d && a <= d && b >= c {
+        // a cd b
+        return true;
+    } else if c <= a && b <= d && b >= c && a <= d {
+        // c ab d
+        return true;
+    } else if a <= c && b <= d && b >= c {
+        // a c b d
+        return true;
+    } else if c <= a && d <= b && a <= d {
+        // c a d b
+        return true;
+    }
+    false
+}
@@ -0,0 +1,423 @@
+use crate::error::{first_problem, parse_number, Error, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::input::{blocks, lines};
+use crate::solution::{Answer, Solution};
+
+/// Each move is `[num_to_move, from_stack, to_stack]` with stacks numbered from 1.
+pub type Move = [u32; 3];
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Parsed = (Vec<Vec<char>>, Vec<Move>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, Error> {
+        Ok(parse_crates(input)?)
+    }
+
+    fn part_one(&self, (container, moves): &Self::Parsed) -> Result<Answer, Error> {
+        Ok(part_one(container, moves)?.into())
+    }
+
+    fn part_two(&self, (container, moves): &Self::Parsed) -> Result<Answer, Error> {
+        Ok(part_two(container, moves)?.into())
+    }
+
+    fn validate(&self, input: &str) -> Vec<ParseError> {
+        let mut problems = vec![];
+        let (container, moves) = read_crates(input, &mut problems);
+        check_crate_counts(input, &container, &moves, &mut problems);
+        problems
+    }
+}
+
+/// `size` moves between up to nine stacks. No move empties a stack, so there is always a crate
+/// on top of each one at the end.
+impl Generate for Day5 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let num_stacks = rng.range(2..=9) as usize;
+        let mut stacks: Vec<Vec<char>> = (0..num_stacks)
+            .map(|_| {
+                (0..rng.range(1..=8))
+                    .map(|_| rng.letter(b'A'..=b'Z'))
+                    .collect()
+            })
+            .collect();
+        // With more crates than stacks there is always a stack that can spare one
+        stacks[0].push(rng.letter(b'A'..=b'Z'));
+
+        let height = stacks.iter().map(Vec::len).max().unwrap_or_default();
+        let mut drawing: Vec<String> = (0..height)
+            .rev()
+            .map(|level| {
+                stacks
+                    .iter()
+                    .map(|stack| match stack.get(level) {
+                        Some(item) => format!("[{}]", item),
+                        None => "   ".to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect();
+        drawing.push(
+            (1..=num_stacks)
+                .map(|stack| format!(" {} ", stack))
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+
+        let mut moves = vec![];
+        for _ in 0..size {
+            let spare: Vec<usize> = (0..num_stacks)
+                .filter(|stack| stacks[*stack].len() > 1)
+                .collect();
+            let from = *rng.choose(&spare);
+            let to = (from + 1 + rng.index(num_stacks - 1)) % num_stacks;
+            let num_to_move = rng.range(1..=stacks[from].len() as i64 - 1) as usize;
+            let keep = stacks[from].len() - num_to_move;
+            let moved = stacks[from].split_off(keep);
+            stacks[to].extend(moved);
+            moves.push(format!(
+                "move {} from {} to {}",
+                num_to_move,
+                from + 1,
+                to + 1
+            ));
+        }
+        format!("{}\n\n{}", drawing.join("\n"), moves.join("\n"))
+    }
+}
+
+/// Reads the drawing of the stacks, each listed bottom crate first, and the moves below it.
+pub fn parse_crates(input_text: &str) -> Result<(Vec<Vec<char>>, Vec<Move>), ParseError> {
+    let mut problems = vec![];
+    let (container, moves) = read_crates(input_text, &mut problems);
+    let moves = moves.into_iter().map(|(_, a_move)| a_move).collect();
+    first_problem((container, moves), problems)
+}
+
+/// Reads the drawing of the stacks (bottom crate first) and the moves, each with the line it is
+/// on, skipping over crates and moves with a problem and adding the problem to `problems`.
+fn read_crates<'a>(
+    input_text: &'a str,
+    problems: &mut Vec<ParseError>,
+) -> (Vec<Vec<char>>, Vec<(&'a str, Move)>) {
+    let split_text: Vec<&str> = blocks(input_text);
+    let [drawing, moves_text] = split_text[..] else {
+        problems.push(ParseError::at(
+            input_text,
+            &input_text[input_text.len()..],
+            "a drawing of the stacks and a list of moves separated by a blank line",
+        ));
+        return (vec![], vec![]);
+    };
+
+    let mut drawing_lines: Vec<&str> = lines(drawing).collect();
+    let stack_numbers = drawing_lines.pop().unwrap_or_default();
+    let num_stacks = stack_numbers.split_whitespace().count();
+    if num_stacks == 0 {
+        // Without the stack numbers there's nothing to check the crates or moves against
+        problems.push(ParseError::at(
+            input_text,
+            stack_numbers,
+            "a row of stack numbers",
+        ));
+        return (vec![], vec![]);
+    }
+
+    let mut container: Vec<Vec<char>> = vec![vec![]; num_stacks];
+    for line in drawing_lines.iter().rev() {
+        // Cells are found by byte offset, so anything that isn't ASCII can't be part of the drawing
+        if let Some(offset) = line.find(|c: char| !c.is_ascii()) {
+            problems.push(ParseError::at(
+                input_text,
+                &line[offset..],
+                "a crate like `[A]`",
+            ));
+            continue;
+        }
+        for (stack, cell_start) in (0..line.len()).step_by(4).enumerate() {
+            let cell = &line[cell_start..line.len().min(cell_start + 3)];
+            if cell.trim().is_empty() {
+                continue;
+            }
+
+            let mut chars = cell.chars();
+            match (chars.next(), chars.next(), chars.next()) {
+                (Some('['), Some(item), Some(']')) if stack < num_stacks => {
+                    container[stack].push(item)
+                }
+                (Some('['), Some(_), Some(']')) => problems.push(ParseError::at(
+                    input_text,
+                    cell,
+                    format!("at most {} stacks", num_stacks),
+                )),
+                _ => problems.push(ParseError::at(input_text, cell, "a crate like `[A]`")),
+            }
+        }
+    }
+
+    let moves = lines(moves_text)
+        .filter_map(|line| {
+            parse_move(input_text, line, num_stacks as u32)
+                .map(|a_move| (line, a_move))
+                .map_err(|error| problems.push(error))
+                .ok()
+        })
+        .collect();
+
+    (container, moves)
+}
+
+/// Replays `moves` on how many crates each stack holds, adding a problem for every move that
+/// takes more crates than its stack has. The stack is emptied and the rest still checked.
+fn check_crate_counts(
+    input_text: &str,
+    container: &[Vec<char>],
+    moves: &[(&str, Move)],
+    problems: &mut Vec<ParseError>,
+) {
+    let mut num_crates: Vec<u32> = container.iter().map(|stack| stack.len() as u32).collect();
+    for (line, [num_to_move, from_stack, to_stack]) in moves {
+        let held = num_crates[(from_stack - 1) as usize];
+        if *num_to_move > held {
+            let num_text = line.split_whitespace().nth(1).unwrap_or(line);
+            problems.push(ParseError::at(
+                input_text,
+                num_text,
+                format!(
+                    "at most {} {}, all that stack {} holds",
+                    held,
+                    if held == 1 { "crate" } else { "crates" },
+                    from_stack
+                ),
+            ));
+        }
+        let moved = held.min(*num_to_move);
+        num_crates[(from_stack - 1) as usize] -= moved;
+        num_crates[(to_stack - 1) as usize] += moved;
+    }
+}
+
+fn parse_move(input_text: &str, line: &str, num_stacks: u32) -> Result<Move, ParseError> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let end_of_line = &line[line.len()..];
+    let expect_word = |index: usize, word: &str| match words.get(index) {
+        Some(found) if *found == word => Ok(()),
+        found => Err(ParseError::at(
+            input_text,
+            found.unwrap_or(&end_of_line),
+            format!("`{}`", word),
+        )),
+    };
+    let number_at = |index: usize, expected: &str| match words.get(index) {
+        Some(word) => parse_number::<u32>(input_text, word, expected).map(|num| (num, *word)),
+        None => Err(ParseError::at(input_text, end_of_line, expected)),
+    };
+
+    expect_word(0, "move")?;
+    let (num_to_move, _) = number_at(1, "a number of crates")?;
+    expect_word(2, "from")?;
+    let from_stack = number_at(3, "a stack number")?;
+    expect_word(4, "to")?;
+    let to_stack = number_at(5, "a stack number")?;
+    if let Some(extra) = words.get(6) {
+        return Err(ParseError::at(input_text, extra, "the end of the move"));
+    }
+
+    for (stack, word) in [from_stack, to_stack] {
+        if stack == 0 || stack > num_stacks {
+            return Err(ParseError::at(
+                input_text,
+                word,
+                format!("a stack number from 1 to {}", num_stacks),
+            ));
+        }
+    }
+
+    Ok([num_to_move, from_stack.0, to_stack.0])
+}
+
+/// The crates on top of each stack once the crane has moved them one at a time.
+pub fn part_one(container: &[Vec<char>], moves: &[Move]) -> Result<String, Error> {
+    let mut container = container.to_vec();
+    for current_moves in moves {
+        perform_movement(
+            current_moves[0],
+            current_moves[1],
+            current_moves[2],
+            &mut container,
+        )?
+    }
+
+    get_top_crates(&container)
+}
+
+/// The crates on top of each stack once the crane has moved each lot of crates together.
+pub fn part_two(container: &[Vec<char>], moves: &[Move]) -> Result<String, Error> {
+    let mut container = container.to_vec();
+    for current_moves in moves {
+        perform_multiple_movement(
+            current_moves[0],
+            current_moves[1],
+            current_moves[2],
+            &mut container,
+        )?
+    }
+
+    get_top_crates(&container)
+}
+
+fn get_top_crates(container: &[Vec<char>]) -> Result<String, Error> {
+    container
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            line.last()
+                .copied()
+                .ok_or_else(|| Error::NoAnswer(format!("stack {} ends up empty", index + 1)))
+        })
+        .collect()
+}
+
+fn not_enough_crates(from_stack: u32) -> Error {
+    Error::NoAnswer(format!(
+        "a move takes more crates from stack {} than it holds",
+        from_stack
+    ))
+}
+
+fn perform_movement(
+    num_to_move: u32,
+    from_stack: u32,
+    to_stack: u32,
+    container: &mut [Vec<char>],
+) -> Result<(), Error> {
+    for _ in 0..num_to_move {
+        let temp = container[(from_stack - 1) as usize]
+            .pop()
+            .ok_or_else(|| not_enough_crates(from_stack))?;
+        container[(to_stack - 1) as usize].push(temp);
+    }
+    Ok(())
+}
+
+fn perform_multiple_movement(
+    num_to_move: u32,
+    from_stack: u32,
+    to_stack: u32,
+    container: &mut [Vec<char>],
+) -> Result<(), Error> {
+    let container_len = container[(from_stack - 1) as usize].len();
+    let split_at = container_len
+        .checked_sub(num_to_move as usize)
+        .ok_or_else(|| not_enough_crates(from_stack))?;
+    let mut temp = container[(from_stack - 1) as usize].split_off(split_at);
+    container[(to_stack - 1) as usize].append(&mut temp);
+    Ok(())
+}
+
+#[test]
+fn test_non_ascii_drawing_is_an_error() {
+    let input_text = "[é] [B]\n 1   2\n\nmove 1 from 1 to 2";
+
+    assert_eq!(
+        parse_crates(input_text).unwrap_err(),
+        ParseError::new(1, 2, "a crate like `[A]`")
+    );
+}
+
+#[test]
+fn test_validate_reports_every_problem() {
+    let input_text =
+        "[A] [B]\n 1   2\n\nmove 1 from 1 to 3\nmove 1 from 2 to 1\nmove x from 1 to 2";
+
+    assert_eq!(
+        Day5.validate(input_text),
+        [
+            ParseError::new(4, 18, "a stack number from 1 to 2"),
+            ParseError::new(6, 6, "a number of crates"),
+        ]
+    );
+}
+
+#[test]
+fn test_validate_replays_moves() {
+    let input_text =
+        "    [C]\n[A] [B]\n 1   2\n\nmove 30 from 1 to 2\nmove 2 from 2 to 1\nmove 4 from 2 to 1";
+
+    assert_eq!(
+        Day5.validate(input_text),
+        [
+            ParseError::new(5, 6, "at most 1 crate, all that stack 1 holds"),
+            ParseError::new(7, 6, "at most 1 crate, all that stack 2 holds"),
+        ]
+    );
+    assert!(Day5.part_one(&Day5.parse(input_text).unwrap()).is_err());
+}
+
+/// Draws `container` (bottom crate first) the way the puzzle input does, followed by `moves`.
+#[cfg(test)]
+fn draw_crates(container: &[Vec<char>], moves: &[Move]) -> String {
+    let height = container.iter().map(Vec::len).max().unwrap_or(0);
+    let mut text = String::new();
+    for row in (0..height).rev() {
+        let cells: Vec<String> = container
+            .iter()
+            .map(|stack| {
+                stack
+                    .get(row)
+                    .map_or("   ".to_string(), |item| format!("[{}]", item))
+            })
+            .collect();
+        text += &cells.join(" ");
+        text += "\n";
+    }
+    let numbers: Vec<String> = (1..=container.len())
+        .map(|stack| format!(" {} ", stack))
+        .collect();
+    text += &numbers.join(" ");
+    text += "\n";
+    for [num_to_move, from_stack, to_stack] in moves {
+        text += &format!("\nmove {} from {} to {}", num_to_move, from_stack, to_stack);
+    }
+    text
+}
+
+/// Between one and nine stacks of crates and some moves between them.
+#[cfg(test)]
+fn crates_strategy() -> impl proptest::strategy::Strategy<Value = (Vec<Vec<char>>, Vec<Move>)> {
+    use proptest::prelude::*;
+
+    let stack = proptest::collection::vec(proptest::char::range('A', 'Z'), 0..6);
+    proptest::collection::vec(stack, 1..10).prop_flat_map(|container| {
+        let num_stacks = container.len() as u32;
+        let moves = proptest::collection::vec((0..10u32, 1..=num_stacks, 1..=num_stacks), 1..8)
+            .prop_map(|moves| {
+                moves
+                    .into_iter()
+                    .map(|(num, from, to)| [num, from, to])
+                    .collect()
+            });
+        (Just(container), moves)
+    })
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn drawn_crates_parse_back((container, moves) in crates_strategy()) {
+        let text = draw_crates(&container, &moves);
+
+        proptest::prop_assert_eq!(parse_crates(&text).unwrap(), (container, moves));
+    }
+
+    #[test]
+    fn parse_crates_never_panics(
+        input in "([ \\[\\]A-Z]{0,12}\n){0,4}[ 0-9]{0,12}\n\n(move [0-9]{1,2} from [0-9]{1,2} to [0-9]{1,2}\n?){0,4}|\\PC{0,64}"
+    ) {
+        let _ = parse_crates(&input);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::error::Error;
+use crate::generate::{Generate, Rng};
+use crate::params::{self, Param};
+use crate::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+pub struct Day6 {
+    pub packet_marker: usize,
+    pub message_marker: usize,
+}
+
+impl Default for Day6 {
+    fn default() -> Self {
+        Day6 {
+            packet_marker: 4,
+            message_marker: 14,
+        }
+    }
+}
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Parsed = Vec<char>;
+
+    fn parse(&self, input: &str) -> Result<Vec<char>, Error> {
+        Ok(input.trim_end().chars().collect())
+    }
+
+    fn part_one(&self, signal: &Vec<char>) -> Result<Answer, Error> {
+        Ok(find_first_n_length_unique_window(self.packet_marker, signal)?.into())
+    }
+
+    fn part_two(&self, signal: &Vec<char>) -> Result<Answer, Error> {
+        Ok(find_first_n_length_unique_window(self.message_marker, signal)?.into())
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new(
+                "packet_marker",
+                "How many different characters in a row mark the start of a packet",
+                self.packet_marker as i64,
+            ),
+            Param::new(
+                "message_marker",
+                "How many different characters in a row mark the start of a message",
+                self.message_marker as i64,
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: i64) -> Result<(), String> {
+        match name {
+            "packet_marker" => self.packet_marker = params::positive(value)?,
+            "message_marker" => self.message_marker = params::positive(value)?,
+            _ => return Err(format!("there is no parameter called `{}`", name)),
+        }
+        Ok(())
+    }
+}
+
+/// A signal `size` characters long (at least 14). Before a random point it only uses 13
+/// different letters, after which comes the first run of 14 different ones.
+impl Generate for Day6 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let size = size.max(14);
+        let mut letters: Vec<char> = ('a'..='z').collect();
+        rng.shuffle(&mut letters);
+        let marker_at = rng.index(size - 13);
+
+        let mut signal: Vec<char> = (0..marker_at)
+            .map(|_| *rng.choose(&letters[..13]))
+            .collect();
+        rng.shuffle(&mut letters[..14]);
+        signal.extend(&letters[..14]);
+        signal.extend((signal.len()..size).map(|_| *rng.choose(&letters)));
+        signal.into_iter().collect()
+    }
+}
+
+/// How many characters have been received by the end of the first run of `n` different ones.
+pub fn find_first_n_length_unique_window(n: usize, signal: &[char]) -> Result<u32, Error> {
+    let position_of_n_unique: usize = signal
+        .windows(n)
+        .map(|wind| wind.iter().collect::<HashSet<&char>>())
+        .position(|hs| hs.len() == n)
+        .ok_or_else(|| Error::NoAnswer(format!("no run of {} different characters", n)))?;
+
+    Ok((position_of_n_unique + n) as u32)
+}
@@ -0,0 +1,348 @@
+use crate::error::{first_problem, parse_number, Error, ParseError};
+use crate::generate::{unique_name, Generate, Rng};
+use crate::input::lines;
+use crate::params::{self, Param};
+use crate::solution::{Answer, Solution};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+/// A directory in the file system, as pieced together from the terminal output.
+pub struct Directory {
+    sub_dirs: HashMap<String, Rc<RefCell<Directory>>>,
+    files: Vec<u32>,
+    parent_dir: Option<Rc<RefCell<Directory>>>,
+    size: u32,
+}
+
+impl Default for Directory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Directory {
+    pub fn new() -> Directory {
+        Directory {
+            sub_dirs: HashMap::new(),
+            files: Vec::new(),
+            parent_dir: None,
+            size: 0,
+        }
+    }
+
+    /// The size of every file in the directory and below it, once [`Directory::calc_dir_size`]
+    /// has been called on the root (which [`parse_file_system`] does).
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    pub fn add_sub_dir(&mut self, dir_name: String, new_sub_dir: Rc<RefCell<Directory>>) {
+        self.sub_dirs.insert(dir_name, new_sub_dir);
+    }
+    pub fn add_file(&mut self, new_file_size: u32) {
+        self.files.push(new_file_size);
+    }
+
+    pub fn calc_dir_size(&mut self) -> u32 {
+        self.size += self.files.iter().sum::<u32>();
+
+        for val in self.sub_dirs.values() {
+            self.size += val.borrow_mut().calc_dir_size();
+        }
+        self.size
+    }
+
+    pub fn add_if_less_than_100_000(&self, accumulator: &mut u32) {
+        for sub_dir in self.sub_dirs.values() {
+            sub_dir.borrow().add_if_less_than_100_000(accumulator);
+        }
+        if self.size < 100000 {
+            *accumulator += self.size;
+        }
+    }
+
+    pub fn find_smallest_size_above_threshold(&self, smallest: &mut u32, threshold: u32) {
+        for sub_dir in self.sub_dirs.values() {
+            sub_dir
+                .borrow()
+                .find_smallest_size_above_threshold(smallest, threshold);
+        }
+        if self.size < *smallest && self.size > threshold {
+            *smallest = self.size;
+        }
+    }
+}
+pub struct Day7 {
+    pub disk_size: u32,
+    pub space_needed: u32,
+}
+
+impl Default for Day7 {
+    fn default() -> Self {
+        Day7 {
+            disk_size: 70000000,
+            space_needed: 30000000,
+        }
+    }
+}
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Parsed = Rc<RefCell<Directory>>;
+
+    fn parse(&self, input: &str) -> Result<Rc<RefCell<Directory>>, Error> {
+        Ok(parse_file_system(input)?)
+    }
+
+    fn part_one(&self, root: &Rc<RefCell<Directory>>) -> Result<Answer, Error> {
+        Ok(part_one(root).into())
+    }
+
+    fn part_two(&self, root: &Rc<RefCell<Directory>>) -> Result<Answer, Error> {
+        Ok(part_two(root, self.disk_size, self.space_needed)?.into())
+    }
+
+    fn validate(&self, input: &str) -> Vec<ParseError> {
+        let mut problems = vec![];
+        read_file_system(input, &mut problems);
+        problems
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new(
+                "disk_size",
+                "The total space on the device",
+                self.disk_size.into(),
+            ),
+            Param::new(
+                "space_needed",
+                "How much free space the update needs",
+                self.space_needed.into(),
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: i64) -> Result<(), String> {
+        match name {
+            "disk_size" => self.disk_size = params::non_negative(value)?,
+            "space_needed" => self.space_needed = params::non_negative(value)?,
+            _ => return Err(format!("there is no parameter called `{}`", name)),
+        }
+        Ok(())
+    }
+}
+
+/// A terminal session exploring a random directory tree holding `size` files. The files add up
+/// to less than the size of the disk.
+impl Generate for Day7 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut taken = HashSet::new();
+        let mut dirs = vec![GeneratedDir::default()];
+        let max_file_size = (60_000_000 / size.max(1) as i64).clamp(1, 300_000);
+        for _ in 0..size {
+            if rng.chance(1, 4) {
+                let parent = rng.index(dirs.len());
+                let name = unique_name(rng, &mut taken);
+                dirs[parent].entries.push(format!("dir {}", name));
+                let index = dirs.len();
+                dirs[parent].sub_dirs.push((name, index));
+                dirs.push(GeneratedDir::default());
+            }
+            let dir = rng.index(dirs.len());
+            let name = unique_name(rng, &mut taken);
+            let extension = *rng.choose(&["", ".txt", ".dat", ".log"]);
+            dirs[dir].entries.push(format!(
+                "{} {}{}",
+                rng.range(1..=max_file_size),
+                name,
+                extension
+            ));
+        }
+
+        let mut transcript = vec!["$ cd /".to_string()];
+        write_listing(rng, &mut dirs, 0, &mut transcript);
+        transcript.join("\n")
+    }
+}
+
+#[derive(Default)]
+struct GeneratedDir {
+    entries: Vec<String>,
+    sub_dirs: Vec<(String, usize)>,
+}
+
+/// Lists `dirs[index]` and then moves into each of its sub directories in turn.
+fn write_listing(
+    rng: &mut Rng,
+    dirs: &mut [GeneratedDir],
+    index: usize,
+    transcript: &mut Vec<String>,
+) {
+    transcript.push("$ ls".to_string());
+    rng.shuffle(&mut dirs[index].entries);
+    transcript.extend(dirs[index].entries.iter().cloned());
+    for (name, sub_dir) in dirs[index].sub_dirs.clone() {
+        transcript.push(format!("$ cd {}", name));
+        write_listing(rng, dirs, sub_dir, transcript);
+        transcript.push("$ cd ..".to_string());
+    }
+}
+
+/// Follows the `cd` and `ls` commands in the terminal output to build the directory tree, and
+/// works out the size of every directory in it.
+pub fn parse_file_system(input_text: &str) -> Result<Rc<RefCell<Directory>>, ParseError> {
+    let mut problems = vec![];
+    let root = read_file_system(input_text, &mut problems);
+    first_problem(root, problems)
+}
+
+/// Follows the terminal output, adding any problems to `problems`. A `cd` that can't be followed
+/// leaves the current directory as it is.
+fn read_file_system(input_text: &str, problems: &mut Vec<ParseError>) -> Rc<RefCell<Directory>> {
+    // First load the file structure into a hella dodgey tree structure
+    let root = Rc::new(RefCell::new(Directory::new()));
+    let mut current = Rc::clone(&root);
+    for line in lines(input_text) {
+        let handled = if let Some(dir_name) = line.strip_prefix("dir ") {
+            handle_dir(dir_name, Rc::clone(&current));
+            Ok(())
+        } else if let Some(command) = line.strip_prefix("$ ") {
+            handle_command(input_text, command, &current, &root).map(|next| {
+                if let Some(x) = next {
+                    current = x;
+                }
+            })
+        } else {
+            handle_file(input_text, line, Rc::clone(&current))
+        };
+        if let Err(error) = handled {
+            problems.push(error);
+        }
+    }
+
+    root.borrow_mut().calc_dir_size();
+    root
+}
+
+fn handle_file(
+    input_text: &str,
+    file_string: &str,
+    current_node: Rc<RefCell<Directory>>,
+) -> Result<(), ParseError> {
+    let (size, _) = file_string.split_once(' ').ok_or_else(|| {
+        ParseError::at(
+            input_text,
+            file_string,
+            "a command, directory or file size and name",
+        )
+    })?;
+    current_node
+        .borrow_mut()
+        .add_file(parse_number(input_text, size, "a file size")?);
+    Ok(())
+}
+
+fn handle_dir(dir_name: &str, current_node: Rc<RefCell<Directory>>) {
+    let subdir = Rc::new(RefCell::new(Directory::new()));
+    subdir.borrow_mut().parent_dir = Some(Rc::clone(&current_node));
+    current_node
+        .borrow_mut()
+        .add_sub_dir(String::from(dir_name), Rc::clone(&subdir));
+}
+
+fn handle_command(
+    input_text: &str,
+    command_str: &str,
+    current_node: &Rc<RefCell<Directory>>,
+    root_node: &Rc<RefCell<Directory>>,
+) -> Result<Option<Rc<RefCell<Directory>>>, ParseError> {
+    if command_str == "ls" {
+        return Ok(None);
+    }
+
+    let Some(target) = command_str.strip_prefix("cd ") else {
+        return Err(ParseError::at(input_text, command_str, "`cd` or `ls`"));
+    };
+    let next_node = match target {
+        "/" => Some(Rc::clone(root_node)),
+        ".." => current_node.borrow().parent_dir.as_ref().map(Rc::clone),
+        _ => current_node.borrow().sub_dirs.get(target).map(Rc::clone),
+    };
+    match next_node {
+        Some(x) => Ok(Some(x)),
+        None if target == ".." => Err(ParseError::at(
+            input_text,
+            target,
+            "a directory to move into, not `..` from the root",
+        )),
+        None => Err(ParseError::at(
+            input_text,
+            target,
+            "a directory listed by an earlier `ls`",
+        )),
+    }
+}
+
+/// The sizes of the directories of at most 100000, added together.
+pub fn part_one(root_node: &Rc<RefCell<Directory>>) -> u32 {
+    // Iterate through tree, if the current dir is less than 100_000 add it to the total
+    let mut total: u32 = 0;
+
+    root_node.borrow_mut().add_if_less_than_100_000(&mut total);
+
+    total
+}
+
+/// The size of the smallest directory that frees up `space_needed` on a disk of `disk_size`
+/// when deleted.
+pub fn part_two(
+    root_node: &Rc<RefCell<Directory>>,
+    disk_size: u32,
+    space_needed: u32,
+) -> Result<u32, Error> {
+    // Iterate through tree, if the current size is less than the current smallest (begins at large num)
+    // and is also large enough to free the required space then it is the new smallest. This is repeated
+    // for every node until our answer is found
+
+    let free_space = disk_size
+        .checked_sub(root_node.borrow().size)
+        .ok_or_else(|| Error::NoAnswer("the files don't fit on the disk".to_string()))?;
+    let amount_of_space_to_free: u32 = space_needed.saturating_sub(free_space);
+
+    let mut size_of_smallest = u32::MAX;
+
+    root_node
+        .borrow()
+        .find_smallest_size_above_threshold(&mut size_of_smallest, amount_of_space_to_free);
+
+    if size_of_smallest == u32::MAX {
+        return Err(Error::NoAnswer(
+            "no directory is big enough to free the space needed".to_string(),
+        ));
+    }
+    Ok(size_of_smallest)
+}
+
+#[test]
+fn test_validate_reports_every_problem() {
+    let input_text = "$ cd /
+$ ls
+dir a
+12 b.txt
+$ cd c
+$ cd a
+big d.txt
+$ cd ..
+$ cd ..";
+
+    assert_eq!(
+        Day7::default().validate(input_text),
+        [
+            ParseError::new(5, 6, "a directory listed by an earlier `ls`"),
+            ParseError::new(7, 1, "a file size"),
+            ParseError::new(9, 6, "a directory to move into, not `..` from the root"),
+        ]
+    );
+}
@@ -0,0 +1,98 @@
+use crate::error::{Error, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::grid::{Grid, ORTHOGONAL};
+use crate::solution::{Answer, Solution};
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Parsed = Grid<u32>;
+
+    fn parse(&self, input: &str) -> Result<Grid<u32>, Error> {
+        Ok(parse_tree_grid(input)?)
+    }
+
+    fn part_one(&self, tree_grid: &Grid<u32>) -> Result<Answer, Error> {
+        Ok(part_one(tree_grid).into())
+    }
+
+    fn part_two(&self, tree_grid: &Grid<u32>) -> Result<Answer, Error> {
+        Ok(part_two(tree_grid).into())
+    }
+}
+
+/// A `size` by `size` grid of trees.
+impl Generate for Day8 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| rng.letter(b'0'..=b'9'))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Reads the height of every tree, one digit each.
+pub fn parse_tree_grid(input_text: &str) -> Result<Grid<u32>, ParseError> {
+    let tree_grid = Grid::parse(input_text, |_, _, height| {
+        height
+            .to_digit(10)
+            .ok_or_else(|| "a tree height".to_string())
+    })?;
+
+    if tree_grid.is_empty() {
+        return Err(ParseError::at(
+            input_text,
+            input_text,
+            "a grid of tree heights",
+        ));
+    }
+    Ok(tree_grid)
+}
+
+/// How many trees can be seen from outside the grid. A tree is visible if every tree between it
+/// and the edge in at least one direction is shorter.
+pub fn part_one(tree_grid: &Grid<u32>) -> u32 {
+    tree_grid
+        .iter()
+        .filter(|(pos, height)| {
+            ORTHOGONAL.iter().any(|step| {
+                tree_grid
+                    .ray(*pos, *step)
+                    .all(|other| tree_grid[other] < **height)
+            })
+        })
+        .count() as u32
+}
+
+/// The highest scenic score of any tree.
+pub fn part_two(tree_grid: &Grid<u32>) -> u32 {
+    tree_grid
+        .positions()
+        .map(|pos| get_scenic_score(tree_grid, pos))
+        .max()
+        .unwrap_or(0)
+}
+
+/// How many trees can be seen in each direction, multiplied together. The view stops at the first
+/// tree at least as tall as this one, which is still counted.
+fn get_scenic_score(tree_grid: &Grid<u32>, pos: (usize, usize)) -> u32 {
+    let current_height = tree_grid[pos];
+    ORTHOGONAL
+        .iter()
+        .map(|step| {
+            let mut view_dist = 0;
+            for other in tree_grid.ray(pos, *step) {
+                view_dist += 1;
+                if tree_grid[other] >= current_height {
+                    break;
+                }
+            }
+            view_dist
+        })
+        .product()
+}
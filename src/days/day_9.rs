@@ -0,0 +1,281 @@
+use crate::error::{parse_number, Error, ParseError};
+use crate::generate::{Generate, Rng};
+use crate::input::lines;
+use crate::point::{Direction, Point2};
+use crate::solution::{Answer, Part, Solution};
+use crate::visualize::{Frame, Recorder, Visualize};
+use std::collections::HashSet;
+
+/// One motion of the head of the rope.
+#[derive(Debug)]
+pub struct Movement {
+    pub direction: Direction,
+    pub num_steps: u32,
+}
+
+impl Movement {
+    pub fn new(dir: Direction, n_steps: u32) -> Movement {
+        Movement {
+            direction: dir,
+            num_steps: n_steps,
+        }
+    }
+}
+
+struct Head {
+    pos: Point2,
+}
+impl Head {
+    pub fn new(start: Point2) -> Head {
+        Head { pos: start }
+    }
+
+    pub fn perform_movement(&mut self, tail: &mut Tail, movement: &Movement) {
+        for _ in 0..movement.num_steps {
+            self.pos += movement.direction.delta();
+            tail.perform_movement(self.pos);
+        }
+    }
+    pub fn perform_movement_multiple(&mut self, tails: &mut Vec<Tail>, movement: &Movement) {
+        for _ in 0..movement.num_steps {
+            self.pos += movement.direction.delta();
+            let mut curr_head_pos = self.pos;
+            for tail in &mut *tails {
+                tail.perform_movement(curr_head_pos);
+
+                curr_head_pos = tail.pos;
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Tail {
+    pos: Point2,
+    hs: HashSet<Point2>,
+}
+
+impl Tail {
+    pub fn new(start: Point2) -> Tail {
+        Tail {
+            pos: start,
+            hs: HashSet::from([start]),
+        }
+    }
+
+    pub fn perform_movement(&mut self, curr_head_pos: Point2) {
+        // Knots only move once they stop touching, including diagonally
+        if self.pos.chebyshev(curr_head_pos) > 1 {
+            self.pos += (curr_head_pos - self.pos).signum();
+            self.hs.insert(self.pos);
+        }
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Parsed = Vec<Movement>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Movement>, Error> {
+        Ok(parse_movements(input)?)
+    }
+
+    fn part_one(&self, movements: &Vec<Movement>) -> Result<Answer, Error> {
+        Ok(part_one(movements).into())
+    }
+
+    fn part_two(&self, movements: &Vec<Movement>) -> Result<Answer, Error> {
+        Ok(part_two(movements).into())
+    }
+}
+
+/// `size` motions of the head.
+impl Generate for Day9 {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}",
+                    rng.choose(&["U", "D", "L", "R"]),
+                    rng.range(1..=20)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// One frame per step of the head, with the places the last knot has been as `#`.
+impl Visualize for Day9 {
+    fn visualize(
+        &self,
+        movements: &Self::Parsed,
+        part: Part,
+        recorder: &mut Recorder,
+    ) -> Result<(), Error> {
+        let (num_tails, labels) = match part {
+            Part::One => (1, "HT"),
+            Part::Two => (9, "H123456789"),
+        };
+
+        // The knots never leave the area the head covers, so that is the size of every frame
+        let (mut min, mut max) = (Point2::ORIGIN, Point2::ORIGIN);
+        let mut head = Head::new(Point2::ORIGIN);
+        for m in movements {
+            head.perform_movement(&mut Tail::new(Point2::ORIGIN), m);
+            min = Point2::new(min.x.min(head.pos.x), min.y.min(head.pos.y));
+            max = Point2::new(max.x.max(head.pos.x), max.y.max(head.pos.y));
+        }
+        let draw = |head: &Head, tails: &[Tail], steps: u32| {
+            let mut frame = Frame::new(
+                (max.x - min.x + 1) as usize,
+                (max.y - min.y + 1) as usize,
+                '.',
+            );
+            let mut set = |pos: Point2, cell: char| {
+                frame.set((pos.x - min.x) as usize, (pos.y - min.y) as usize, cell)
+            };
+            let visited = &tails[tails.len() - 1].hs;
+            for pos in visited {
+                set(*pos, '#');
+            }
+            set(Point2::ORIGIN, 's');
+            // Drawn from the last knot forward so that knots in front cover the ones behind
+            let knots: Vec<(Point2, char)> = std::iter::once(head.pos)
+                .chain(tails.iter().map(|tail| tail.pos))
+                .zip(labels.chars())
+                .collect();
+            for (pos, label) in knots.into_iter().rev() {
+                set(pos, label);
+            }
+            frame.with_caption(format!(
+                "{} steps, {} positions visited",
+                steps,
+                visited.len()
+            ))
+        };
+
+        let mut head = Head::new(Point2::ORIGIN);
+        let mut tails = vec![Tail::new(Point2::ORIGIN); num_tails];
+        let mut steps = 0;
+        for m in movements {
+            for _ in 0..m.num_steps {
+                head.perform_movement_multiple(&mut tails, &Movement::new(m.direction, 1));
+                steps += 1;
+
+                if !recorder.step(|| draw(&head, &tails, steps)) {
+                    return Ok(());
+                }
+            }
+        }
+        recorder.last(|| draw(&head, &tails, steps));
+        Ok(())
+    }
+}
+
+/// Reads the motions of the head, one like `R 4` per line.
+pub fn parse_movements(input_text: &str) -> Result<Vec<Movement>, ParseError> {
+    lines(input_text)
+        .map(|line| parse_movement(input_text, line))
+        .collect()
+}
+
+fn parse_movement(input_text: &str, line: &str) -> Result<Movement, ParseError> {
+    let (dir, n_steps) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::at(input_text, line, "a direction and a number of steps"))?;
+    let direction = Direction::from_letter(dir)
+        .ok_or_else(|| ParseError::at(input_text, dir, "`R`, `L`, `U` or `D`"))?;
+    Ok(Movement::new(
+        direction,
+        parse_number(input_text, n_steps, "a number of steps")?,
+    ))
+}
+
+/// How many positions the tail of a rope two knots long visits.
+pub fn part_one(movements: &[Movement]) -> u32 {
+    let mut head = Head::new(Point2::ORIGIN);
+    let mut tail = Tail::new(Point2::ORIGIN);
+
+    for m in movements {
+        head.perform_movement(&mut tail, m);
+    }
+
+    tail.hs.len() as u32
+}
+
+/// How many positions the tail of a rope ten knots long visits.
+pub fn part_two(movements: &[Movement]) -> u32 {
+    let mut head = Head::new(Point2::ORIGIN);
+    let mut tails: Vec<Tail> = vec![Tail::new(Point2::ORIGIN); 9];
+
+    for m in movements {
+        head.perform_movement_multiple(&mut tails, m);
+    }
+
+    tails[tails.len() - 1].hs.len() as u32
+}
+#[test]
+fn test_visualize_part_two() {
+    let input_text = "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20";
+    let mut frames: Vec<Frame> = vec![];
+    let mut recorder = Recorder::new(&mut frames, 10, None);
+
+    Day9.visualize(&Day9.parse(input_text).unwrap(), Part::Two, &mut recorder)
+        .unwrap();
+
+    assert_eq!(recorder.finish().unwrap(), 11);
+    let last = frames.last().unwrap();
+    assert_eq!(last.caption, "96 steps, 36 positions visited");
+    assert_eq!(
+        last.to_string(),
+        "\
+H.........................
+1.........................
+2.........................
+3.........................
+4.........................
+5.........................
+6.........................
+7.........................
+8.........................
+9.........................
+#.............###.........
+#............#...#........
+.#..........#.....#.......
+..#..........#.....#......
+...#........#.......#.....
+....#......s.........#....
+.....#..............#.....
+......#............#......
+.......#..........#.......
+........#........#........
+.........########........."
+    );
+}
+// If it is the first one that has a distance greater than 1.5 then move to head pos
+// Otherwise use the same motion as the first one for all other tails if the distance gets greater than 1.5
+
+// ..........................
+// ..........................
+// ..........................
+// ..........................
+// ..........................
+// ..........................
+// ..........................
+// ..........H12345..........    Just because the one before it moved diagonally doesnt necessarily mean we should move by the same delta maybe?
+// ................6.........
+// ................7.........
+// ................8.........
+// ................9.........
+// ..........................
+// ..........................
+// ..........................
+// ...........s..............  (9 covers s)
+// ..........................
+// ..........................
+// ..........................
+// ..........................
+// ..........................
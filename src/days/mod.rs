@@ -0,0 +1,115 @@
+use crate::generate::Generator;
+use crate::solution::Runnable;
+use crate::visualize::Visualization;
+
+pub mod day_1;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_2;
+pub mod day_20;
+pub mod day_21;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+
+/// Every day that has been solved so far, in order.
+pub fn all() -> Vec<Box<dyn Runnable>> {
+    vec![
+        Box::new(day_1::Day1),
+        Box::new(day_2::Day2),
+        Box::new(day_3::Day3),
+        Box::new(day_4::Day4),
+        Box::new(day_5::Day5),
+        Box::new(day_6::Day6::default()),
+        Box::new(day_7::Day7::default()),
+        Box::new(day_8::Day8),
+        Box::new(day_9::Day9),
+        Box::new(day_10::Day10),
+        Box::new(day_11::Day11::default()),
+        Box::new(day_12::Day12),
+        Box::new(day_13::Day13),
+        Box::new(day_14::Day14),
+        Box::new(day_15::Day15::default()),
+        Box::new(day_17::Day17::default()),
+        Box::new(day_18::Day18),
+        Box::new(day_19::Day19::default()),
+        Box::new(day_20::Day20::default()),
+        Box::new(day_21::Day21),
+    ]
+}
+
+/// Look up a single day, returning `None` if it hasn't been solved.
+pub fn get(day: u8) -> Option<Box<dyn Runnable>> {
+    all().into_iter().find(|solution| solution.day() == day)
+}
+
+/// The days whose simulations can be watched with [`Visualization`].
+pub fn visualizations() -> Vec<Box<dyn Visualization>> {
+    vec![
+        Box::new(day_9::Day9),
+        Box::new(day_12::Day12),
+        Box::new(day_14::Day14),
+        Box::new(day_17::Day17::default()),
+    ]
+}
+
+/// The days that can make up random inputs with [`Generator`].
+pub fn generators() -> Vec<Box<dyn Generator>> {
+    vec![
+        Box::new(day_1::Day1),
+        Box::new(day_2::Day2),
+        Box::new(day_3::Day3),
+        Box::new(day_4::Day4),
+        Box::new(day_5::Day5),
+        Box::new(day_6::Day6::default()),
+        Box::new(day_7::Day7::default()),
+        Box::new(day_8::Day8),
+        Box::new(day_9::Day9),
+        Box::new(day_10::Day10),
+        Box::new(day_11::Day11::default()),
+        Box::new(day_12::Day12),
+        Box::new(day_13::Day13),
+        Box::new(day_14::Day14),
+        Box::new(day_15::Day15::default()),
+        Box::new(day_17::Day17::default()),
+        Box::new(day_18::Day18),
+        Box::new(day_19::Day19::default()),
+        Box::new(day_20::Day20::default()),
+        Box::new(day_21::Day21),
+    ]
+}
+
+#[cfg(test)]
+crate::fixtures::fixture_tests! {
+    fixtures_day_1 => 1,
+    fixtures_day_2 => 2,
+    fixtures_day_3 => 3,
+    fixtures_day_4 => 4,
+    fixtures_day_5 => 5,
+    fixtures_day_6 => 6,
+    fixtures_day_7 => 7,
+    fixtures_day_8 => 8,
+    fixtures_day_9 => 9,
+    fixtures_day_10 => 10,
+    fixtures_day_11 => 11,
+    fixtures_day_12 => 12,
+    fixtures_day_13 => 13,
+    fixtures_day_14 => 14,
+    fixtures_day_15 => 15,
+    fixtures_day_17 => 17,
+    fixtures_day_18 => 18,
+    fixtures_day_19 => 19,
+    fixtures_day_20 => 20,
+    fixtures_day_21 => 21,
+}
@@ -0,0 +1,195 @@
+use crate::input::InputError;
+use crate::progress::Cancelled;
+use nom::error::{VerboseError, VerboseErrorKind};
+use nom::IResult;
+use std::fmt;
+
+/// What the nom based parsers return, so that failures can be turned into a [`ParseError`] with
+/// [`ParseError::from_nom`].
+pub type NomResult<'a, T> = IResult<&'a str, T, VerboseError<&'a str>>;
+
+/// Everything that can go wrong while solving a day.
+#[derive(Debug)]
+pub enum Error {
+    /// The puzzle input couldn't be read.
+    Input(InputError),
+    /// The puzzle input isn't in the format the day expects.
+    Parse(ParseError),
+    /// The input is well formed but the puzzle it describes has no answer, e.g. day 12's end
+    /// can't be reached from the start.
+    NoAnswer(String),
+    /// The part was stopped before it finished, see [`crate::progress::Budget`].
+    Cancelled(Cancelled),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Input(error) => write!(f, "{}", error),
+            Error::Parse(error) => write!(f, "{}", error),
+            Error::NoAnswer(reason) => write!(f, "no answer: {}", reason),
+            Error::Cancelled(reason) => write!(f, "cancelled: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Input(error) => Some(error),
+            Error::Parse(error) => Some(error),
+            Error::NoAnswer(_) | Error::Cancelled(_) => None,
+        }
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(error: InputError) -> Self {
+        Error::Input(error)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+/// Where in the input parsing failed and what should have been there instead. Lines and
+/// columns both start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    /// An error pointing at the start of `position`, which must be a slice of `input` (as
+    /// returned by `lines`, `blocks`, `split` and friends). The line and column are worked out
+    /// from where `position` sits inside `input`.
+    pub fn at(input: &str, position: &str, expected: impl Into<String>) -> Self {
+        let mut offset = (position.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+        }
+    }
+
+    /// Converts a failed nom parse of some slice of `input` into an error. The innermost
+    /// `context` the parser was wrapped in is used as the description of what was expected.
+    pub fn from_nom(input: &str, error: nom::Err<VerboseError<&str>>) -> Self {
+        let errors = match error {
+            nom::Err::Error(error) | nom::Err::Failure(error) => error.errors,
+            nom::Err::Incomplete(_) => vec![],
+        };
+
+        let position = errors
+            .first()
+            .map_or(&input[input.len()..], |(rest, _)| *rest);
+        let expected = errors
+            .iter()
+            .find_map(|(_, kind)| match kind {
+                VerboseErrorKind::Context(context) => Some(context.to_string()),
+                _ => None,
+            })
+            .or_else(|| {
+                errors.first().map(|(_, kind)| match kind {
+                    VerboseErrorKind::Char(c) => format!("`{}`", c),
+                    VerboseErrorKind::Nom(kind) => kind.description().to_lowercase(),
+                    VerboseErrorKind::Context(context) => context.to_string(),
+                })
+            })
+            .unwrap_or_else(|| "more input".to_string());
+
+        ParseError::at(input, position, expected)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Turns everything a parser that carries on past problems found into the result of one that
+/// stops at the first, so that `parse` and `validate` can share the same code.
+pub fn first_problem<T>(parsed: T, problems: Vec<ParseError>) -> Result<T, ParseError> {
+    match problems.into_iter().next() {
+        Some(problem) => Err(problem),
+        None => Ok(parsed),
+    }
+}
+
+/// Parses `token` (a slice of `input`) as a number, describing it as `expected` if it isn't one.
+pub fn parse_number<T: std::str::FromStr>(
+    input: &str,
+    token: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::at(input, token, expected))
+}
+
+#[test]
+fn test_error_position_from_slice() {
+    let input = "1-2,3-4\nab-6,7-8\n";
+    let bad = input.lines().nth(1).unwrap().split('-').next().unwrap();
+
+    assert_eq!(
+        ParseError::at(input, bad, "a section id"),
+        ParseError::new(2, 1, "a section id")
+    );
+    assert_eq!(
+        ParseError::at(input, &input[12..], "a section id").column,
+        5
+    );
+    assert_eq!(
+        ParseError::new(2, 1, "a section id").to_string(),
+        "line 2, column 1: expected a section id"
+    );
+}
+
+#[test]
+fn test_error_from_nom_uses_innermost_context() {
+    use nom::bytes::complete::tag;
+    use nom::error::context;
+    use nom::sequence::preceded;
+
+    let input = "Valve AA\nValve BB has";
+    let line = input.lines().nth(1).unwrap();
+    let error = preceded(
+        context("`Valve `", tag::<_, _, VerboseError<&str>>("Valve ")),
+        context("` flow`", tag(" flow")),
+    )(line)
+    .unwrap_err();
+
+    assert_eq!(
+        ParseError::from_nom(input, error),
+        ParseError::new(2, 7, "` flow`")
+    );
+}
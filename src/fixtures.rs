@@ -0,0 +1,161 @@
+//! Regression tests built from the examples in the puzzle descriptions, plus other helpers
+//! shared by tests.
+//!
+//! Each day's examples live in `inputs/tests/day_N/`. Every `NAME.txt` in there is an input and
+//! `NAME.toml` next to it holds the expected answers in the same form as a day in `answers.toml`:
+//!
+//! ```toml
+//! part_one = 24000
+//! part_two = 45000
+//! ```
+//!
+//! A part without an expected answer isn't checked, and neither is an example without any yet,
+//! like the one `aoc new` starts a day with. Examples that use different parameters to the real
+//! puzzle (day 15's row) give them in a `[params]` table, as in `aoc.toml`:
+//!
+//! ```toml
+//! part_one = 26
+//!
+//! [params]
+//! row = 10
+//! ```
+//!
+//! Adding a new example only needs the two files; [`fixture_tests!`] runs everything it finds in
+//! the day's directory.
+
+use crate::answers::DayAnswers;
+use crate::days;
+use crate::params::ParamOverrides;
+use crate::solution::Part;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// An empty directory under the system temp directory for tests that write files. `name` has to
+/// be unique to the calling test.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc_2022_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// The contents of an example's `NAME.toml`.
+#[derive(Deserialize)]
+struct Expected {
+    #[serde(flatten)]
+    answers: DayAnswers,
+    #[serde(default)]
+    params: BTreeMap<String, i64>,
+}
+
+fn fixture_dir(day: u8) -> PathBuf {
+    Path::new("./inputs/tests").join(format!("day_{}", day))
+}
+
+/// Runs both parts of `day` against every example in its fixture directory and panics with a
+/// list of every mismatch if any answer is wrong.
+pub fn check_fixtures(day: u8) {
+    check_fixtures_in(&fixture_dir(day), day);
+}
+
+/// [`check_fixtures`] for the examples in `dir`.
+pub fn check_fixtures_in(dir: &Path, day: u8) {
+    let mut inputs: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap_or_else(|error| panic!("could not read `{}`: {}", dir.display(), error))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    inputs.sort();
+    assert!(!inputs.is_empty(), "no examples in `{}`", dir.display());
+
+    let mut num_checked = 0;
+    let mut failures = vec![];
+    for input_path in &inputs {
+        let answers_path = input_path.with_extension("toml");
+        let expected: Expected = fs::read_to_string(&answers_path)
+            .map_err(|error| error.to_string())
+            .and_then(|text| toml::from_str(&text).map_err(|error| error.to_string()))
+            .unwrap_or_else(|error| panic!("`{}`: {}", answers_path.display(), error));
+        if Part::BOTH
+            .iter()
+            .all(|part| expected.answers.expected(*part).is_none())
+        {
+            continue;
+        }
+        let input = fs::read_to_string(input_path).unwrap();
+
+        let mut solution = days::get(day).unwrap_or_else(|| panic!("Day {} isn't registered", day));
+        let mut overrides = ParamOverrides::default();
+        for (name, value) in &expected.params {
+            overrides.set(day, name, *value);
+        }
+        overrides
+            .apply(solution.as_mut())
+            .unwrap_or_else(|error| panic!("`{}`: {}", answers_path.display(), error));
+
+        let parsed = match solution.parse_erased(&input) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                failures.push(format!("{}: {}", input_path.display(), error));
+                continue;
+            }
+        };
+
+        for part in Part::BOTH {
+            let Some(expected) = expected.answers.expected(part) else {
+                continue;
+            };
+            num_checked += 1;
+
+            match solution.solve(parsed.as_ref(), part) {
+                Ok(answer) if answer.to_string() == expected => {}
+                Ok(answer) => failures.push(format!(
+                    "{} {}: expected {}, got {}",
+                    input_path.display(),
+                    part,
+                    expected,
+                    answer
+                )),
+                Err(error) => {
+                    failures.push(format!("{} {}: {}", input_path.display(), part, error))
+                }
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Day {} examples failed:\n{}",
+        day,
+        failures.join("\n")
+    );
+    if num_checked == 0 {
+        eprintln!(
+            "Day {} has no expected answers in `{}` yet",
+            day,
+            dir.display()
+        );
+    }
+}
+
+/// Generates a `#[test]` per day that checks all of that day's examples:
+///
+/// ```ignore
+/// fixture_tests! {
+///     fixtures_day_1 => 1,
+///     fixtures_day_2 => 2,
+/// }
+/// ```
+macro_rules! fixture_tests {
+    ($($name:ident => $day:expr),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                $crate::fixtures::check_fixtures($day);
+            }
+        )*
+    };
+}
+pub(crate) use fixture_tests;
@@ -0,0 +1,164 @@
+use crate::solution::{Runnable, Solution};
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+/// A small seeded random number generator (SplitMix64). It is written out here rather than
+/// taken from a crate so that a seed keeps producing the same input from one version to the
+/// next.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number from `0` up to but not including `bound`, which must be more than 0.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "can't pick a number below 0");
+        // Multiplying rather than taking the remainder keeps small bounds close to uniform
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "can't pick from an empty range");
+        let span = end.abs_diff(start);
+        if span == u64::MAX {
+            return self.next_u64() as i64;
+        }
+        start.wrapping_add(self.below(span + 1) as i64)
+    }
+
+    /// An index into something `len` long, which must not be empty.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// `true` with probability `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for end in (1..items.len()).rev() {
+            items.swap(end, self.index(end + 1));
+        }
+    }
+
+    /// A random letter from `letters`, e.g. `b'a'..=b'z'`.
+    pub fn letter(&mut self, letters: RangeInclusive<u8>) -> char {
+        self.range(*letters.start() as i64..=*letters.end() as i64) as u8 as char
+    }
+}
+
+/// Random puzzle inputs for stress tests and benchmarks. Implemented next to the [`Solution`] it
+/// belongs to, and run through [`Generator`] so that every day can be driven from the same list.
+///
+/// Generated inputs always pass [`Solution::validate`] and are built so that both parts have an
+/// answer, unless a day's implementation says otherwise.
+pub trait Generate: Solution {
+    /// An input roughly `size` big, where each day decides what size counts (lines, monkeys,
+    /// sensors, ...). The same `rng` state always gives the same input.
+    fn generate(&self, rng: &mut Rng, size: usize) -> String;
+}
+
+/// Object safe view of [`Generate`], in the same way [`Runnable`] is of [`Solution`].
+pub trait Generator: Runnable {
+    fn generate_input(&self, seed: u64, size: usize) -> String;
+}
+
+impl<G: Generate + Send + Sync> Generator for G
+where
+    G::Parsed: 'static,
+{
+    fn generate_input(&self, seed: u64, size: usize) -> String {
+        self.generate(&mut Rng::new(seed), size)
+    }
+}
+
+/// Four lowercase letters that haven't been handed out yet, for days that name things.
+pub(crate) fn unique_name(rng: &mut Rng, taken: &mut HashSet<String>) -> String {
+    loop {
+        let name: String = (0..4).map(|_| rng.letter(b'a'..=b'z')).collect();
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+#[test]
+fn test_rng_is_repeatable() {
+    let mut first = Rng::new(2022);
+    let mut second = Rng::new(2022);
+    let numbers: Vec<u64> = (0..10).map(|_| first.next_u64()).collect();
+
+    assert_eq!(
+        numbers,
+        (0..10).map(|_| second.next_u64()).collect::<Vec<_>>()
+    );
+    assert_ne!(numbers, {
+        let mut other = Rng::new(2023);
+        (0..10).map(|_| other.next_u64()).collect::<Vec<_>>()
+    });
+}
+
+#[test]
+fn test_rng_ranges() {
+    let mut rng = Rng::new(7);
+    for _ in 0..1000 {
+        assert!((-3..=3).contains(&rng.range(-3..=3)));
+        assert!(rng.below(5) < 5);
+        assert!(('A'..='C').contains(&rng.letter(b'A'..=b'C')));
+    }
+    assert_eq!(rng.range(4..=4), 4);
+    rng.range(i64::MIN..=i64::MAX);
+
+    let mut items = [1, 2, 3, 4, 5];
+    rng.shuffle(&mut items);
+    items.sort_unstable();
+    assert_eq!(items, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_generated_inputs_are_valid_and_solvable() {
+    use crate::solution::Part;
+
+    for generator in crate::days::generators() {
+        let day = generator.day();
+        for (seed, size) in [(0, 1), (1, 5), (2, 40)] {
+            let input = generator.generate_input(seed, size);
+            assert_eq!(input, generator.generate_input(seed, size));
+            assert_eq!(generator.validate_input(&input), vec![], "day {}", day);
+
+            // Day 19 is too slow to solve in a debug build, and day 15's part one counts every
+            // position along a row millions of positions long
+            for part in Part::BOTH {
+                if day == 19 || (day, part) == (15, Part::One) {
+                    continue;
+                }
+                if let Err(error) = generator.run(&input, part) {
+                    panic!(
+                        "day {} {} with seed {}: {}\n{}",
+                        day, part, seed, error, input
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,298 @@
+use crate::error::ParseError;
+use crate::input::lines;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// The four steps to a cell's neighbours, as `(dx, dy)`: left, right, up then down.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// [`ORTHOGONAL`] followed by the four diagonal steps.
+pub const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (-1, 0),
+    (1, 0),
+    (0, -1),
+    (0, 1),
+    (-1, -1),
+    (1, -1),
+    (-1, 1),
+    (1, 1),
+];
+
+/// A rectangle of cells stored row by row. Cells are indexed by `(x, y)`, with `y` counting down
+/// from the top row the way the puzzle inputs are written.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| cell(x, y))
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from its rows, or `None` if they aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a map with one character per cell, such as day 8's trees or day 12's heights.
+    /// `cell` is given each character with its position and returns what should have been there
+    /// instead if it isn't valid. Errors point at the offending character, or at the end of a row
+    /// that is a different length to the first.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(usize, usize, char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in lines(input).enumerate() {
+            let mut row_width = 0;
+            for (x, (offset, c)) in line.char_indices().enumerate() {
+                let value = cell(x, y, c)
+                    .map_err(|expected| ParseError::at(input, &line[offset..], expected))?;
+                cells.push(value);
+                row_width += 1;
+            }
+
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                let offset = line
+                    .char_indices()
+                    .nth(width)
+                    .map_or(line.len(), |(offset, _)| offset);
+                return Err(ParseError::at(
+                    input,
+                    &line[offset..],
+                    format!("a row of {} cells", width),
+                ));
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        self.index_of(x, y).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        self.index_of(x, y).map(|index| &mut self.cells[index])
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    /// The position one `step` away from `(x, y)`, or `None` if that is off the grid.
+    pub fn step(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The positions up to four steps away that are on the grid, in [`ORTHOGONAL`] order.
+    pub fn neighbours(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |step| self.step(position, *step))
+    }
+
+    /// Like [`Grid::neighbours`] but including diagonals.
+    pub fn all_neighbours(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ALL_DIRECTIONS
+            .iter()
+            .filter_map(move |step| self.step(position, *step))
+    }
+
+    /// The positions passed going from `start` in steps of `step` until the edge of the grid,
+    /// not including `start` itself.
+    pub fn ray(
+        &self,
+        start: (usize, usize),
+        step: (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        std::iter::successors(self.step(start, step), move |position| {
+            self.step(*position, step)
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells[x..]
+            .iter()
+            .step_by(self.width.max(1))
+            .take(self.height)
+    }
+
+    /// Swaps rows and columns, so that `(x, y)` ends up at `(y, x)`.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(cell).collect(),
+        }
+    }
+
+    /// Draws the grid as text, one line per row with no trailing newline.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        match self.get(position) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} is outside a {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(position) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside a {}x{} grid", position, width, height),
+        }
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|cell| *cell))
+    }
+}
+
+#[test]
+fn test_parse_and_render() {
+    let grid = Grid::parse("#.\n.#\n##", |_, _, c| Ok(c)).unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (2, 3));
+    assert_eq!(grid[(1, 2)], '#');
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.to_string(), "#.\n.#\n##");
+    assert_eq!(grid.transpose().to_string(), "#.#\n.##");
+    assert_eq!(grid.column(1).collect::<String>(), ".##");
+}
+
+#[test]
+fn test_parse_errors() {
+    let digit = |_, _, c: char| c.to_digit(10).ok_or("a digit".to_string());
+
+    assert_eq!(
+        Grid::parse("123\n4x6", digit).unwrap_err(),
+        ParseError::new(2, 2, "a digit")
+    );
+    assert_eq!(
+        Grid::parse("123\n4567", digit).unwrap_err(),
+        ParseError::new(2, 4, "a row of 3 cells")
+    );
+    assert_eq!(
+        Grid::parse("123\n45", digit).unwrap_err(),
+        ParseError::new(2, 3, "a row of 3 cells")
+    );
+}
+
+#[test]
+fn test_neighbours_and_rays() {
+    let grid = Grid::new(3, 3, 0);
+
+    assert_eq!(
+        grid.neighbours((0, 0)).collect::<Vec<_>>(),
+        [(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbours((1, 1)).count(), 4);
+    assert_eq!(grid.all_neighbours((1, 1)).count(), 8);
+    assert_eq!(grid.all_neighbours((2, 2)).count(), 3);
+    assert_eq!(
+        grid.ray((2, 1), (-1, 0)).collect::<Vec<_>>(),
+        [(1, 1), (0, 1)]
+    );
+    assert_eq!(grid.ray((0, 0), (0, -1)).count(), 0);
+}
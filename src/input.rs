@@ -0,0 +1,210 @@
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where a day's puzzle input should be read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// A single input file, used as is for whichever day is being solved.
+    File(PathBuf),
+    /// A directory laid out like `./inputs`, holding `input_day_N.txt` for each day.
+    Directory(PathBuf),
+    /// Standard input.
+    Stdin,
+}
+
+impl InputSource {
+    /// Works out what kind of source `arg` is. `-` means stdin, an existing directory is read as
+    /// a directory of inputs and anything else is treated as a file.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            return InputSource::Stdin;
+        }
+
+        let path = PathBuf::from(arg);
+        if path.is_dir() {
+            InputSource::Directory(path)
+        } else {
+            InputSource::File(path)
+        }
+    }
+
+    /// Whether the same source can supply input for more than one day.
+    pub fn serves_many_days(&self) -> bool {
+        matches!(self, InputSource::Directory(_))
+    }
+
+    /// The path the input for `day` is read from, or `None` for stdin.
+    pub fn path_for_day(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Directory(dir) => Some(dir.join(format!("input_day_{}.txt", day))),
+            InputSource::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        match self.path_for_day(day) {
+            Some(path) => read_file(&path),
+            None => {
+                let mut input_text = String::new();
+                io::stdin()
+                    .read_to_string(&mut input_text)
+                    .map_err(InputError::Stdin)?;
+                Ok(input_text)
+            }
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|source| {
+        if source.kind() == io::ErrorKind::NotFound {
+            InputError::NotFound(path.to_path_buf())
+        } else {
+            InputError::Unreadable {
+                path: path.to_path_buf(),
+                source,
+            }
+        }
+    })
+}
+
+/// Converts Windows (`\r\n`) and old Mac (`\r`) line endings to `\n` and drops any trailing
+/// newlines, so every day sees the same text regardless of how the input was saved.
+pub fn normalise(raw: &str) -> String {
+    raw.replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .trim_end_matches('\n')
+        .to_string()
+}
+
+/// The lines of `text` without their line endings. Works with either `\n` or `\r\n` endings.
+pub fn lines(text: &str) -> std::str::Lines<'_> {
+    text.lines()
+}
+
+/// Splits `text` into groups of lines separated by one or more blank lines. Leading whitespace on
+/// the first line of a block is kept (day 5's crate drawing depends on it) and each block is
+/// returned without a trailing line ending. Works with either `\n` or `\r\n` endings.
+pub fn blocks(text: &str) -> Vec<&str> {
+    let mut blocks = vec![];
+    let mut block_start: Option<usize> = None;
+    let mut block_end = 0;
+    let mut offset = 0;
+
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            if let Some(start) = block_start.take() {
+                blocks.push(&text[start..block_end]);
+            }
+        } else {
+            if block_start.is_none() {
+                block_start = Some(offset);
+            }
+            block_end = offset + content.len();
+        }
+        offset += line.len();
+    }
+    if let Some(start) = block_start {
+        blocks.push(&text[start..block_end]);
+    }
+
+    blocks
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Unreadable { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => {
+                write!(f, "input file `{}` does not exist", path.display())
+            }
+            InputError::Unreadable { path, source } => {
+                write!(
+                    f,
+                    "could not read input file `{}`: {}",
+                    path.display(),
+                    source
+                )
+            }
+            InputError::Stdin(source) => write!(f, "could not read input from stdin: {}", source),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::NotFound(_) => None,
+            InputError::Unreadable { source, .. } | InputError::Stdin(source) => Some(source),
+        }
+    }
+}
+
+#[test]
+fn test_directory_source_finds_day_file() {
+    let source = InputSource::from_arg("./inputs");
+
+    assert_eq!(source, InputSource::Directory(PathBuf::from("./inputs")));
+    assert_eq!(
+        source.path_for_day(13),
+        Some(PathBuf::from("./inputs/input_day_13.txt"))
+    );
+}
+
+#[test]
+fn test_missing_file_reports_path() {
+    let source = InputSource::File(PathBuf::from("./inputs/does_not_exist.txt"));
+
+    let error = source.read(1).unwrap_err();
+    assert!(matches!(error, InputError::NotFound(_)));
+    assert!(error.to_string().contains("./inputs/does_not_exist.txt"));
+}
+
+#[test]
+fn test_file_source_is_used_for_any_day() {
+    let source = InputSource::from_arg("./inputs/tests/day_13/example.txt");
+
+    assert!(!source.serves_many_days());
+    assert!(source.read(13).unwrap().starts_with("[1,1,3,1,1]"));
+}
+
+#[test]
+fn test_normalise_line_endings() {
+    assert_eq!(normalise("a\r\nb\r\n\r\nc\r\n"), "a\nb\n\nc");
+    assert_eq!(normalise("a\nb\n\nc\n"), "a\nb\n\nc");
+    assert_eq!(normalise("a\rb"), "a\nb");
+    assert_eq!(normalise(">><<"), ">><<");
+}
+
+#[test]
+fn test_blocks_ignore_line_endings() {
+    let lf = "    [D]\n[N] [C]\n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\n";
+    let crlf = lf.replace('\n', "\r\n");
+
+    assert_eq!(
+        blocks(lf),
+        vec!["    [D]\n[N] [C]", "move 1 from 2 to 1\nmove 3 from 1 to 3"]
+    );
+    assert_eq!(
+        blocks(&crlf),
+        vec![
+            "    [D]\r\n[N] [C]",
+            "move 1 from 2 to 1\r\nmove 3 from 1 to 3"
+        ]
+    );
+}
+
+#[test]
+fn test_blocks_collapse_repeated_blank_lines() {
+    assert_eq!(blocks("1\n2\n\n\n3\n\n"), vec!["1\n2", "3"]);
+    assert!(blocks("\n\n").is_empty());
+}
@@ -0,0 +1,40 @@
+//! Solutions to Advent of Code 2022, and the tools around them used by the `aoc` binary.
+//!
+//! Each day lives in [`days`] as `days::day_N`, with a function that parses the input into the
+//! day's types, `part_one` and `part_two` functions that solve them (taking the numbers the puzzle
+//! text gives as arguments) and a `DayN` type that puts them together as a [`solution::Solution`]:
+//!
+//! ```
+//! use aoc_2022::days::day_1::{self, Day1};
+//! use aoc_2022::solution::{Answer, Part, Runnable};
+//!
+//! let input = "1000\n2000\n\n4000\n\n5000\n6000";
+//! let elves = day_1::parse_elves(input).unwrap();
+//! assert_eq!(day_1::part_one(&elves), 11000);
+//! assert_eq!(Day1.run(input, Part::Two).unwrap(), Answer::Signed(18000));
+//! ```
+//!
+//! Days whose puzzles fix some numbers, like day 15's row, keep them as fields on `DayN` (see
+//! [`params`]), and [`days::all`] lists every day to drive them together.
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod cycle;
+pub mod days;
+pub mod error;
+#[cfg(test)]
+mod fixtures;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod memory;
+pub mod params;
+pub mod point;
+pub mod progress;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod visualize;
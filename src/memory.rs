@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// The system allocator, counting allocations while [`measure`] is running. Install it in a
+/// binary with `#[global_allocator]`; outside of `measure` it costs one atomic load per call.
+pub struct CountingAllocator;
+
+static COUNTING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES_ALLOCATED: AtomicU64 = AtomicU64::new(0);
+/// Bytes allocated minus bytes freed since counting started. Memory allocated beforehand can be
+/// freed while counting, so this can go negative.
+static NET_BYTES: AtomicI64 = AtomicI64::new(0);
+static PEAK_NET_BYTES: AtomicI64 = AtomicI64::new(0);
+/// Only one measurement can use the counters at a time.
+static MEASURING: Mutex<()> = Mutex::new(());
+
+impl CountingAllocator {
+    fn record(&self, allocated: usize, freed: usize) {
+        if !COUNTING.load(Ordering::Relaxed) {
+            return;
+        }
+        if allocated > 0 {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            BYTES_ALLOCATED.fetch_add(allocated as u64, Ordering::Relaxed);
+        }
+        let change = allocated as i64 - freed as i64;
+        let net = NET_BYTES.fetch_add(change, Ordering::Relaxed) + change;
+        PEAK_NET_BYTES.fetch_max(net, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.record(layout.size(), 0);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        self.record(layout.size(), 0);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.record(0, layout.size());
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        self.record(new_size, layout.size());
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// What was allocated while running something. Allocations made by other threads at the same
+/// time are counted too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct MemoryStats {
+    /// Calls to allocate or resize memory.
+    pub allocations: u64,
+    /// The total size of every allocation, even the ones freed again straight away.
+    pub bytes_allocated: u64,
+    /// The most memory that was held at once on top of what was already allocated beforehand.
+    pub peak_bytes: u64,
+}
+
+impl fmt::Display for MemoryStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "allocs {:>9}  allocated {:>10}  peak {:>10}",
+            self.allocations,
+            format_bytes(self.bytes_allocated),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+/// Runs `f`, counting what it allocates. The memory stats are `None` if [`CountingAllocator`]
+/// isn't the global allocator, since nothing gets counted then.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    let _guard = MEASURING
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    COUNTING.store(true, Ordering::SeqCst);
+
+    // Checks the allocator is counting before the allocations that matter are made
+    drop(std::hint::black_box(Box::new(0u8)));
+    let installed = ALLOCATIONS.swap(0, Ordering::Relaxed) > 0;
+    BYTES_ALLOCATED.store(0, Ordering::Relaxed);
+    NET_BYTES.store(0, Ordering::Relaxed);
+    PEAK_NET_BYTES.store(0, Ordering::Relaxed);
+
+    let result = f();
+
+    COUNTING.store(false, Ordering::SeqCst);
+    let stats = installed.then(|| MemoryStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        bytes_allocated: BYTES_ALLOCATED.load(Ordering::Relaxed),
+        peak_bytes: PEAK_NET_BYTES.load(Ordering::Relaxed).max(0) as u64,
+    });
+    (result, stats)
+}
+
+#[cfg(test)]
+#[global_allocator]
+static TEST_ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_measure_counts_allocations() {
+    let (sum, stats) = measure(|| {
+        let numbers: Vec<u64> = (0..1000).collect();
+        let strings: Vec<String> = (0..10).map(|n| n.to_string()).collect();
+        numbers.iter().sum::<u64>() + strings.len() as u64
+    });
+    let stats = stats.unwrap();
+
+    assert_eq!(sum, 499_510);
+    // Other tests allocate on their own threads at the same time, so these are lower bounds
+    assert!(stats.allocations >= 12);
+    assert!(stats.bytes_allocated >= 8000 + 10 + 24 * 10);
+    assert!(stats.peak_bytes <= stats.bytes_allocated);
+}
+
+#[test]
+fn test_format_bytes() {
+    assert_eq!(format_bytes(512), "512 B");
+    assert_eq!(format_bytes(1536), "1.5 KiB");
+    assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+}
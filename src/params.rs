@@ -0,0 +1,276 @@
+use crate::solution::Runnable;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// One of the numbers a day's puzzle text gives rather than its input, like day 15's row or how
+/// many rocks day 17 drops, with the value the day is using.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub value: i64,
+}
+
+impl Param {
+    pub fn new(name: &'static str, description: &'static str, value: i64) -> Self {
+        Param {
+            name,
+            description,
+            value,
+        }
+    }
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.name, self.value)
+    }
+}
+
+/// `value` for a parameter that counts something, so has to be at least 1.
+pub fn positive<T: TryFrom<i64>>(value: i64) -> Result<T, String> {
+    if value < 1 {
+        return Err("it has to be at least 1".to_string());
+    }
+    T::try_from(value).map_err(|_| "it is too large".to_string())
+}
+
+/// `value` for a parameter that can't be negative.
+pub fn non_negative<T: TryFrom<i64>>(value: i64) -> Result<T, String> {
+    if value < 0 {
+        return Err("it can't be negative".to_string());
+    }
+    T::try_from(value).map_err(|_| "it is too large".to_string())
+}
+
+/// Values to solve with instead of the days' defaults, as read from `aoc.toml`:
+///
+/// ```toml
+/// [day_15]
+/// row = 10
+/// search_area = 20
+/// ```
+///
+/// or given on the command line as `--param 15.row=10`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ParamOverrides {
+    days: BTreeMap<u8, BTreeMap<String, i64>>,
+}
+
+impl ParamOverrides {
+    pub fn load(path: &Path) -> Result<Self, ParamError> {
+        let text = std::fs::read_to_string(path).map_err(|source| ParamError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(&text, path)
+    }
+
+    /// Reads overrides written like `aoc.toml`, where `path` is only used in errors.
+    pub fn parse(text: &str, path: &Path) -> Result<Self, ParamError> {
+        let tables: BTreeMap<String, BTreeMap<String, i64>> =
+            toml::from_str(text).map_err(|source| ParamError::Parse {
+                path: path.to_path_buf(),
+                source,
+            })?;
+
+        let mut overrides = ParamOverrides::default();
+        for (table, values) in tables {
+            let Some(day) = table.strip_prefix("day_").and_then(|day| day.parse().ok()) else {
+                return Err(ParamError::NotADay {
+                    path: path.to_path_buf(),
+                    table,
+                });
+            };
+            overrides.days.insert(day, values);
+        }
+        Ok(overrides)
+    }
+
+    /// Adds a `--param` argument written as `DAY.NAME=VALUE`, replacing any value the same
+    /// parameter already had.
+    pub fn add_arg(&mut self, arg: &str) -> Result<(), ParamError> {
+        let bad_arg = || ParamError::BadArg(arg.to_string());
+        let (key, value) = arg.split_once('=').ok_or_else(bad_arg)?;
+        let (day, name) = key.split_once('.').ok_or_else(bad_arg)?;
+        let day = day.trim().parse().map_err(|_| bad_arg())?;
+        let value = value.trim().parse().map_err(|_| bad_arg())?;
+        self.set(day, name.trim(), value);
+        Ok(())
+    }
+
+    pub fn set(&mut self, day: u8, name: &str, value: i64) {
+        self.days
+            .entry(day)
+            .or_default()
+            .insert(name.to_string(), value);
+    }
+
+    /// Changes `solution`'s parameters to the ones given for its day, leaving the rest at their
+    /// defaults. Overrides for other days are ignored.
+    pub fn apply<R: Runnable + ?Sized>(&self, solution: &mut R) -> Result<(), ParamError> {
+        let day = solution.day();
+        let Some(values) = self.days.get(&day) else {
+            return Ok(());
+        };
+
+        let known = solution.current_params();
+        for (name, value) in values {
+            if !known.iter().any(|param| param.name == name) {
+                return Err(ParamError::Unknown {
+                    day,
+                    name: name.clone(),
+                    known: known.iter().map(|param| param.name).collect(),
+                });
+            }
+            solution
+                .override_param(name, *value)
+                .map_err(|reason| ParamError::Invalid {
+                    day,
+                    name: name.clone(),
+                    value: *value,
+                    reason,
+                })?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum ParamError {
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    /// A table in the file that isn't named like `day_15`.
+    NotADay { path: PathBuf, table: String },
+    /// A `--param` argument that isn't written as `DAY.NAME=VALUE`.
+    BadArg(String),
+    Unknown {
+        day: u8,
+        name: String,
+        known: Vec<&'static str>,
+    },
+    Invalid {
+        day: u8,
+        name: String,
+        value: i64,
+        reason: String,
+    },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::Read { path, source } => {
+                write!(f, "Could not read `{}`: {}", path.display(), source)
+            }
+            ParamError::Parse { path, source } => {
+                write!(f, "`{}` is not valid: {}", path.display(), source)
+            }
+            ParamError::NotADay { path, table } => write!(
+                f,
+                "`{}` has a table `[{}]`, but tables have to be named like `[day_15]`",
+                path.display(),
+                table
+            ),
+            ParamError::BadArg(arg) => write!(
+                f,
+                "`{}` should be written as DAY.NAME=VALUE, e.g. `15.row=10`",
+                arg
+            ),
+            ParamError::Unknown { day, name, known } if known.is_empty() => {
+                write!(f, "Day {} has no parameters, so can't set `{}`", day, name)
+            }
+            ParamError::Unknown { day, name, known } => write!(
+                f,
+                "Day {} has no parameter called `{}`, only {}",
+                day,
+                name,
+                known.join(", ")
+            ),
+            ParamError::Invalid {
+                day,
+                name,
+                value,
+                reason,
+            } => write!(f, "Day {}'s `{}` can't be {}: {}", day, name, value, reason),
+        }
+    }
+}
+
+impl std::error::Error for ParamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParamError::Read { source, .. } => Some(source),
+            ParamError::Parse { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn test_overrides_from_file_and_args() {
+    let mut overrides = ParamOverrides::parse(
+        "[day_15]\nrow = 10\nsearch_area = 20\n",
+        Path::new("aoc.toml"),
+    )
+    .unwrap();
+    overrides.add_arg("15.row=11").unwrap();
+    overrides.add_arg("17.part_one_rocks=5").unwrap();
+
+    let mut expected = ParamOverrides::default();
+    expected.set(15, "row", 11);
+    expected.set(15, "search_area", 20);
+    expected.set(17, "part_one_rocks", 5);
+    assert_eq!(overrides, expected);
+
+    assert!(matches!(
+        ParamOverrides::parse("[day15]\nrow = 10\n", Path::new("aoc.toml")),
+        Err(ParamError::NotADay { .. })
+    ));
+    for arg in ["15.row", "row=10", "15.row=ten", "x.row=10"] {
+        assert!(matches!(
+            ParamOverrides::default().add_arg(arg),
+            Err(ParamError::BadArg(_))
+        ));
+    }
+}
+
+#[test]
+fn test_apply_overrides() {
+    use crate::days::day_15::Day15;
+    use crate::solution::Solution;
+
+    let mut overrides = ParamOverrides::default();
+    overrides.set(15, "row", 10);
+    overrides.set(1, "row", 5);
+    let mut day_15 = Day15::default();
+    overrides.apply(&mut day_15).unwrap();
+    assert!(day_15
+        .params()
+        .iter()
+        .any(|param| param.name == "row" && param.value == 10));
+
+    overrides.set(15, "search_area", -1);
+    assert_eq!(
+        overrides.apply(&mut day_15).unwrap_err().to_string(),
+        "Day 15's `search_area` can't be -1: it can't be negative"
+    );
+
+    let mut day_1 = crate::days::day_1::Day1;
+    assert_eq!(
+        overrides.apply(&mut day_1).unwrap_err().to_string(),
+        "Day 1 has no parameters, so can't set `row`"
+    );
+    overrides.set(15, "rows", 10);
+    assert_eq!(
+        overrides.apply(&mut day_15).unwrap_err().to_string(),
+        "Day 15 has no parameter called `rows`, only row, search_area"
+    );
+}
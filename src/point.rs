@@ -0,0 +1,324 @@
+use crate::error::{parse_number, ParseError};
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A position or offset on a 2D plane. Like [`crate::grid::Grid`], `y` grows downwards, so
+/// [`Direction::Up`] is towards smaller `y`.
+///
+/// Points are ordered by `x` and then `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A position or offset in 3D space, ordered by `x`, then `y`, then `z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+
+    /// The number of steps between the points moving only up, down, left and right.
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The number of steps between the points when diagonal steps are allowed too.
+    pub fn chebyshev(self, other: Point2) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Each coordinate clamped to -1, 0 or 1, i.e. the single step that goes most directly
+    /// towards this offset.
+    pub fn signum(self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The four points a step up, down, left or right of this one.
+    pub fn neighbours(self) -> impl Iterator<Item = Point2> {
+        Direction::ALL
+            .into_iter()
+            .map(move |dir| self + dir.delta())
+    }
+
+    /// The eight points touching this one, including diagonally.
+    pub fn all_neighbours(self) -> impl Iterator<Item = Point2> {
+        (-1..=1)
+            .flat_map(|y| (-1..=1).map(move |x| Point2::new(x, y)))
+            .filter(|delta| *delta != Point2::ORIGIN)
+            .map(move |delta| self + delta)
+    }
+
+    /// Parses `text`, a slice of `input`, as `x,y`. Errors point into `input`.
+    pub fn parse(input: &str, text: &str) -> Result<Self, ParseError> {
+        let (x, y) = text
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(input, text, "a point like `498,4`"))?;
+        Ok(Point2::new(
+            parse_number(input, x.trim(), "an x coordinate")?,
+            parse_number(input, y.trim(), "a y coordinate")?,
+        ))
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Point3) -> i64 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    pub fn signum(self) -> Point3 {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// One step along each axis in each direction.
+    pub const UNITS: [Point3; 6] = [
+        Point3::new(1, 0, 0),
+        Point3::new(-1, 0, 0),
+        Point3::new(0, 1, 0),
+        Point3::new(0, -1, 0),
+        Point3::new(0, 0, 1),
+        Point3::new(0, 0, -1),
+    ];
+
+    /// The six points sharing a face with this one.
+    pub fn neighbours(self) -> impl Iterator<Item = Point3> {
+        Point3::UNITS.into_iter().map(move |unit| self + unit)
+    }
+
+    /// The smallest and largest value of each coordinate across `points`, as the opposite
+    /// corners of the box containing them all. `None` if there are no points.
+    pub fn bounds(points: impl IntoIterator<Item = Point3>) -> Option<(Point3, Point3)> {
+        points.into_iter().fold(None, |bounds, point| {
+            let (min, max) = bounds.unwrap_or((point, point));
+            Some((
+                Point3::new(min.x.min(point.x), min.y.min(point.y), min.z.min(point.z)),
+                Point3::new(max.x.max(point.x), max.y.max(point.y), max.z.max(point.z)),
+            ))
+        })
+    }
+
+    /// Parses `text`, a slice of `input`, as `x,y,z`. Errors point into `input`.
+    pub fn parse(input: &str, text: &str) -> Result<Self, ParseError> {
+        let coordinates = text
+            .split(',')
+            .map(|num| parse_number(input, num.trim(), "a whole number coordinate"))
+            .collect::<Result<Vec<i64>, _>>()?;
+        match coordinates[..] {
+            [x, y, z] => Ok(Point3::new(x, y, z)),
+            _ => Err(ParseError::at(
+                input,
+                text,
+                "three coordinates like `2,2,2`",
+            )),
+        }
+    }
+}
+
+/// A step up, down, left or right. `Up` is towards smaller `y`, the way maps are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub fn delta(self) -> Point2 {
+        match self {
+            Direction::Up => Point2::new(0, -1),
+            Direction::Down => Point2::new(0, 1),
+            Direction::Left => Point2::new(-1, 0),
+            Direction::Right => Point2::new(1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    /// The direction a quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.turn_right().opposite()
+    }
+
+    /// Reads the single letter the puzzles use: `U`, `D`, `L` or `R`.
+    pub fn from_letter(letter: &str) -> Option<Direction> {
+        match letter {
+            "U" => Some(Direction::Up),
+            "D" => Some(Direction::Down),
+            "L" => Some(Direction::Left),
+            "R" => Some(Direction::Right),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_ops {
+    ($point:ident { $($field:ident),* }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($field: -self.$field),* }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, scale: i64) -> $point {
+                $point { $($field: self.$field * scale),* }
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+impl fmt::Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+impl FromStr for Point2 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Point2::parse(s, s)
+    }
+}
+
+impl FromStr for Point3 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Point3::parse(s, s)
+    }
+}
+
+#[test]
+fn test_distances() {
+    let a = Point2::new(8, 7);
+    let b = Point2::new(2, 10);
+
+    assert_eq!(a.manhattan(b), 9);
+    assert_eq!(a.chebyshev(b), 6);
+    assert_eq!((b - a).signum(), Point2::new(-1, 1));
+    assert_eq!(Point3::new(1, 2, 3).manhattan(Point3::new(-1, 2, 7)), 6);
+    assert_eq!(Point3::new(1, 2, 3).chebyshev(Point3::new(-1, 2, 7)), 4);
+}
+
+#[test]
+fn test_neighbours_and_directions() {
+    assert_eq!(Point2::ORIGIN.neighbours().count(), 4);
+    assert!(Point2::ORIGIN
+        .all_neighbours()
+        .all(|point| point.chebyshev(Point2::ORIGIN) == 1));
+    assert_eq!(Point2::ORIGIN.all_neighbours().count(), 8);
+    assert_eq!(Point3::ORIGIN.neighbours().count(), 6);
+
+    for dir in Direction::ALL {
+        assert_eq!(dir.delta() + dir.opposite().delta(), Point2::ORIGIN);
+        assert_eq!(dir.turn_left().turn_right(), dir);
+    }
+    assert_eq!(Direction::Up.turn_right(), Direction::Right);
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!("498,4".parse(), Ok(Point2::new(498, 4)));
+    assert_eq!("2, -2, 5".parse(), Ok(Point3::new(2, -2, 5)));
+    assert_eq!(
+        "1,2".parse::<Point3>(),
+        Err(ParseError::new(1, 1, "three coordinates like `2,2,2`"))
+    );
+    assert_eq!(
+        "1;2".parse::<Point2>(),
+        Err(ParseError::new(1, 1, "a point like `498,4`"))
+    );
+    assert_eq!(
+        Point3::bounds([Point3::new(1, 5, 2), Point3::new(3, 0, 2)]),
+        Some((Point3::new(1, 0, 2), Point3::new(3, 5, 2)))
+    );
+}
@@ -0,0 +1,311 @@
+use crate::error::Error;
+use crate::solution::Part;
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Why a part was stopped before it finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cancelled {
+    /// The part ran for longer than its [`Budget`] allows.
+    TimedOut(Duration),
+    /// The [`CancelFlag`] was set, e.g. by Ctrl-C.
+    Interrupted,
+}
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cancelled::TimedOut(timeout) => write!(f, "ran out of time after {:?}", timeout),
+            Cancelled::Interrupted => write!(f, "interrupted"),
+        }
+    }
+}
+
+/// A switch shared between threads to stop every part running under a [`Budget`] holding it.
+#[derive(Debug, Clone, Default)]
+pub struct CancelFlag(Arc<AtomicBool>);
+
+impl CancelFlag {
+    pub fn new() -> Self {
+        CancelFlag::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// How far a part has got, as passed to the callback given to [`Budget::with_progress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    pub day: u8,
+    pub part: Part,
+    /// How much of the work is done, in whatever the day counts (rows, blueprints, rocks, ...).
+    pub done: u64,
+    /// How much work there is in total, if the day knows.
+    pub total: Option<u64>,
+    pub elapsed: Duration,
+}
+
+impl fmt::Display for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {} {}: ", self.day, self.part)?;
+        match self.total {
+            Some(total) if total > 0 => write!(
+                f,
+                "{} of {} ({:.0}%)",
+                self.done,
+                total,
+                self.done as f64 / total as f64 * 100.0
+            )?,
+            _ => write!(f, "{} done", self.done)?,
+        }
+        write!(f, " after {:.1?}", self.elapsed)
+    }
+}
+
+type ProgressCallback = Arc<dyn Fn(&Progress) + Send + Sync>;
+
+/// Limits on how long each part may run, and where to report how it is getting on. The default
+/// has no limits and reports nothing.
+///
+/// Parts only notice their budget at the points where they call [`check`] or [`update`], which
+/// the long running ones (days 15, 17 and 19) do regularly. The others finish well within any
+/// sensible timeout anyway.
+#[derive(Clone, Default)]
+pub struct Budget {
+    timeout: Option<Duration>,
+    cancel: CancelFlag,
+    report: Option<(Duration, ProgressCallback)>,
+}
+
+impl Budget {
+    pub fn new() -> Self {
+        Budget::default()
+    }
+
+    /// Stops each part once it has been running for `timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Stops every part, including ones that haven't started yet, once `cancel` is set.
+    pub fn with_cancel_flag(mut self, cancel: CancelFlag) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    /// Calls `report` with each part's progress, at most once every `every`. It is called on
+    /// whichever thread is solving the part.
+    pub fn with_progress(
+        mut self,
+        every: Duration,
+        report: impl Fn(&Progress) + Send + Sync + 'static,
+    ) -> Self {
+        self.report = Some((every, Arc::new(report)));
+        self
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.is_cancelled()
+    }
+
+    /// Runs `work` as `part` of `day` under this budget, so that [`check`] and [`update`] inside
+    /// it return an [`Error::Cancelled`] once the budget runs out. If the cancel flag is already
+    /// set `work` isn't run at all.
+    pub fn run<T>(
+        &self,
+        day: u8,
+        part: Part,
+        work: impl FnOnce() -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        if self.is_cancelled() {
+            return Err(Error::Cancelled(Cancelled::Interrupted));
+        }
+
+        let started = Instant::now();
+        let active = Active {
+            day,
+            part,
+            started,
+            timeout: self.timeout,
+            cancel: self.cancel.clone(),
+            report: self.report.clone(),
+            next_report: started
+                + self
+                    .report
+                    .as_ref()
+                    .map_or(Duration::ZERO, |(every, _)| *every),
+            done: 0,
+            total: None,
+            calls: 0,
+        };
+        let _guard = ActiveGuard(ACTIVE.with_borrow_mut(|current| current.replace(active)));
+        work()
+    }
+}
+
+/// The budget of the part running on this thread.
+struct Active {
+    day: u8,
+    part: Part,
+    started: Instant,
+    timeout: Option<Duration>,
+    cancel: CancelFlag,
+    report: Option<(Duration, ProgressCallback)>,
+    next_report: Instant,
+    done: u64,
+    total: Option<u64>,
+    calls: u32,
+}
+
+thread_local! {
+    static ACTIVE: RefCell<Option<Active>> = const { RefCell::new(None) };
+}
+
+/// Puts back whatever budget was running before, even if the part panics.
+struct ActiveGuard(Option<Active>);
+
+impl Drop for ActiveGuard {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        ACTIVE.with_borrow_mut(|current| *current = previous);
+    }
+}
+
+/// Reading the clock costs more than the work between most checks, so it is only read this often.
+const CLOCK_EVERY: u32 = 1024;
+
+/// Returns an [`Error::Cancelled`] if the part's [`Budget`] has run out, for solvers to call
+/// (and pass on with `?`) regularly while working. Does nothing outside [`Budget::run`].
+pub fn check() -> Result<(), Error> {
+    poll(None)
+}
+
+/// Like [`check`], but also records that `done` out of `total` units of work are finished so
+/// that it can be reported.
+pub fn update(done: u64, total: Option<u64>) -> Result<(), Error> {
+    poll(Some((done, total)))
+}
+
+fn poll(progress: Option<(u64, Option<u64>)>) -> Result<(), Error> {
+    ACTIVE.with_borrow_mut(|active| {
+        let Some(active) = active else {
+            return Ok(());
+        };
+        if let Some((done, total)) = progress {
+            active.done = done;
+            active.total = total;
+        }
+        if active.cancel.is_cancelled() {
+            return Err(Error::Cancelled(Cancelled::Interrupted));
+        }
+
+        active.calls = active.calls.wrapping_add(1);
+        if active.calls % CLOCK_EVERY != 0 {
+            return Ok(());
+        }
+        let now = Instant::now();
+        let elapsed = now - active.started;
+        if let Some(timeout) = active.timeout.filter(|timeout| elapsed >= *timeout) {
+            return Err(Error::Cancelled(Cancelled::TimedOut(timeout)));
+        }
+        if let Some((every, report)) = &active.report {
+            if now >= active.next_report {
+                report(&Progress {
+                    day: active.day,
+                    part: active.part,
+                    done: active.done,
+                    total: active.total,
+                    elapsed,
+                });
+                active.next_report = now + *every;
+            }
+        }
+        Ok(())
+    })
+}
+
+#[test]
+fn test_checks_do_nothing_without_a_budget() {
+    assert!(check().is_ok());
+    assert!(update(5, Some(10)).is_ok());
+}
+
+#[test]
+fn test_timeout_and_cancel_stop_work() {
+    let endless = || -> Result<(), Error> {
+        loop {
+            check()?;
+        }
+    };
+
+    let timed = Budget::new().with_timeout(Duration::from_millis(20));
+    assert!(matches!(
+        timed.run(15, Part::Two, endless),
+        Err(Error::Cancelled(Cancelled::TimedOut(_)))
+    ));
+
+    let cancel = CancelFlag::new();
+    let cancellable = Budget::new().with_cancel_flag(cancel.clone());
+    let stopper = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(20));
+        cancel.cancel();
+    });
+    assert!(matches!(
+        cancellable.run(19, Part::One, endless),
+        Err(Error::Cancelled(Cancelled::Interrupted))
+    ));
+    stopper.join().unwrap();
+
+    // Nothing more is started once the flag is set
+    let mut started = false;
+    let result = cancellable.run(1, Part::One, || {
+        started = true;
+        Ok(())
+    });
+    assert!(result.is_err() && !started);
+    assert!(check().is_ok(), "the budget only applies inside `run`");
+}
+
+#[test]
+fn test_progress_is_reported() {
+    let reports = Arc::new(std::sync::Mutex::new(vec![]));
+    let budget = Budget::new().with_progress(Duration::ZERO, {
+        let reports = Arc::clone(&reports);
+        move |progress| reports.lock().unwrap().push(*progress)
+    });
+
+    let answer = budget.run(15, Part::Two, || {
+        for row in 0..10_000 {
+            update(row, Some(10_000))?;
+        }
+        Ok(42)
+    });
+
+    assert_eq!(answer.unwrap(), 42);
+    let reports = reports.lock().unwrap();
+    assert!(!reports.is_empty());
+    assert!(reports.iter().all(|progress| progress.day == 15
+        && progress.part == Part::Two
+        && progress.total == Some(10_000)));
+    assert_eq!(
+        Progress {
+            day: 15,
+            part: Part::Two,
+            done: 1,
+            total: Some(4),
+            elapsed: Duration::from_millis(1500),
+        }
+        .to_string(),
+        "Day 15 Part Two: 1 of 4 (25%) after 1.5s"
+    );
+}
@@ -0,0 +1,197 @@
+use crate::answers::CheckResult;
+use crate::solution::{Answer, Part};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::fmt::Write as _;
+
+/// One part of one day as written by `aoc run --format json` (one object per line) or
+/// `--format csv`. Times are in nanoseconds, like the bench reports.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PartRecord {
+    pub day: u8,
+    pub part: u8,
+    /// `None` if parsing or solving failed, in which case `error` says why.
+    pub answer: Option<String>,
+    pub answer_type: Option<AnswerType>,
+    pub error: Option<String>,
+    pub parse_ns: u64,
+    /// `None` if parsing failed, so the part was never solved.
+    pub solve_ns: Option<u64>,
+    /// SHA-256 of the input as read, before line endings are normalised.
+    pub input_sha256: String,
+    /// Only filled in when answers are being checked.
+    pub check: Option<CheckStatus>,
+    pub expected: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnswerType {
+    Signed,
+    Unsigned,
+    Text,
+}
+
+impl AnswerType {
+    pub fn of(answer: &Answer) -> Self {
+        match answer {
+            Answer::Signed(_) => AnswerType::Signed,
+            Answer::Unsigned(_) => AnswerType::Unsigned,
+            Answer::Text(_) => AnswerType::Text,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            AnswerType::Signed => "signed",
+            AnswerType::Unsigned => "unsigned",
+            AnswerType::Text => "text",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl CheckStatus {
+    fn name(&self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "pass",
+            CheckStatus::Fail => "fail",
+            CheckStatus::Missing => "missing",
+        }
+    }
+}
+
+impl PartRecord {
+    pub fn new(day: u8, part: Part, input_sha256: &str, parse_ns: u64) -> Self {
+        PartRecord {
+            day,
+            part: part.number(),
+            answer: None,
+            answer_type: None,
+            error: None,
+            parse_ns,
+            solve_ns: None,
+            input_sha256: input_sha256.to_string(),
+            check: None,
+            expected: None,
+        }
+    }
+
+    pub fn with_answer(mut self, answer: &Answer) -> Self {
+        self.answer = Some(answer.to_string());
+        self.answer_type = Some(AnswerType::of(answer));
+        self
+    }
+
+    pub fn with_error(mut self, error: impl ToString) -> Self {
+        self.error = Some(error.to_string());
+        self
+    }
+
+    pub fn with_check(mut self, result: &CheckResult) -> Self {
+        let (status, expected) = match result {
+            CheckResult::Pass => (CheckStatus::Pass, self.answer.clone()),
+            CheckResult::Fail { expected } => (CheckStatus::Fail, Some(expected.clone())),
+            CheckResult::Missing => (CheckStatus::Missing, None),
+        };
+        self.check = Some(status);
+        self.expected = expected;
+        self
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("part records always serialise")
+    }
+
+    /// The column names for [`PartRecord::to_csv`], in the same order.
+    pub const CSV_HEADER: &'static str =
+        "day,part,answer,answer_type,error,parse_ns,solve_ns,input_sha256,check,expected";
+
+    /// The record as a CSV row. Missing values are left empty and fields containing commas,
+    /// quotes or line breaks (day 10's render) are quoted.
+    pub fn to_csv(&self) -> String {
+        let fields: [Cow<str>; 10] = [
+            self.day.to_string().into(),
+            self.part.to_string().into(),
+            self.answer.as_deref().unwrap_or_default().into(),
+            self.answer_type.map_or("", |kind| kind.name()).into(),
+            self.error.as_deref().unwrap_or_default().into(),
+            self.parse_ns.to_string().into(),
+            self.solve_ns
+                .map(|ns| ns.to_string())
+                .unwrap_or_default()
+                .into(),
+            self.input_sha256.as_str().into(),
+            self.check.map_or("", |status| status.name()).into(),
+            self.expected.as_deref().unwrap_or_default().into(),
+        ];
+        fields
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+fn csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\"")).into()
+    } else {
+        field.into()
+    }
+}
+
+/// The SHA-256 of `input` as lowercase hex, so that results can be tied to the input they came
+/// from without including it.
+pub fn input_hash(input: &str) -> String {
+    let digest = Sha256::digest(input.as_bytes());
+    let mut hex = String::with_capacity(digest.len() * 2);
+    for byte in digest {
+        write!(hex, "{:02x}", byte).expect("writing to a String can't fail");
+    }
+    hex
+}
+
+#[test]
+fn test_input_hash() {
+    assert_eq!(
+        input_hash("abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn test_record_as_json_and_csv() {
+    let record = PartRecord {
+        solve_ns: Some(2500),
+        ..PartRecord::new(10, Part::Two, "abc123", 1000)
+    }
+    .with_answer(&Answer::Text("#.\n\"#".to_string()))
+    .with_check(&CheckResult::Fail {
+        expected: "##".to_string(),
+    });
+
+    assert_eq!(
+        record.to_json(),
+        r###"{"day":10,"part":2,"answer":"#.\n\"#","answer_type":"text","error":null,"parse_ns":1000,"solve_ns":2500,"input_sha256":"abc123","check":"fail","expected":"##"}"###
+    );
+    assert_eq!(
+        record.to_csv(),
+        "10,2,\"#.\n\"\"#\",text,,1000,2500,abc123,fail,##"
+    );
+    assert_eq!(
+        PartRecord::CSV_HEADER.split(',').count(),
+        PartRecord::new(1, Part::One, "", 0)
+            .to_csv()
+            .split(',')
+            .count()
+    );
+}
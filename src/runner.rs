@@ -0,0 +1,199 @@
+use crate::answers::AnswerStore;
+use crate::progress::Budget;
+use crate::report::{self, PartRecord};
+use crate::solution::{Part, Runnable};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::time::Instant;
+
+/// The results of solving one day.
+#[derive(Debug, Clone)]
+pub struct DayRun {
+    pub day: u8,
+    /// Why the input couldn't be parsed, if it couldn't. Every record then holds this error.
+    pub parse_error: Option<String>,
+    pub records: Vec<PartRecord>,
+}
+
+/// Parses `input` once and solves each of `parts` with it under `budget`, timing every stage.
+/// Answers are checked against `answers` if it is given.
+pub fn run_day(
+    solution: &dyn Runnable,
+    input: &str,
+    parts: &[Part],
+    answers: Option<&AnswerStore>,
+    budget: &Budget,
+) -> DayRun {
+    let day = solution.day();
+    let input_sha256 = report::input_hash(input);
+
+    let start = Instant::now();
+    let parsed = solution.parse_erased(input);
+    let parse_ns = start.elapsed().as_nanos() as u64;
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            let records = parts
+                .iter()
+                .map(|part| PartRecord::new(day, *part, &input_sha256, parse_ns).with_error(&error))
+                .collect();
+            return DayRun {
+                day,
+                parse_error: Some(error.to_string()),
+                records,
+            };
+        }
+    };
+
+    let records = parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let result = budget.run(day, *part, || solution.solve(parsed.as_ref(), *part));
+            let record = PartRecord {
+                solve_ns: Some(start.elapsed().as_nanos() as u64),
+                ..PartRecord::new(day, *part, &input_sha256, parse_ns)
+            };
+
+            match (result, answers) {
+                (Ok(answer), Some(store)) => record
+                    .with_answer(&answer)
+                    .with_check(&store.check(day, *part, &answer)),
+                (Ok(answer), None) => record.with_answer(&answer),
+                (Err(error), _) => record.with_error(&error),
+            }
+        })
+        .collect();
+    DayRun {
+        day,
+        parse_error: None,
+        records,
+    }
+}
+
+/// Calls `work` on each of `items`, running at most `jobs` of them at once on separate threads.
+/// `done` is called on the calling thread with each result in the order of `items`, as soon as
+/// that result and all the ones before it are ready. That keeps the output in order while slow
+/// items (day 19) are still running, without them holding up the items queued behind them.
+pub fn run_in_order<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    work: impl Fn(&T) -> R + Sync,
+    mut done: impl FnMut(&T, R),
+) {
+    let next_item = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let (next_item, work) = (&next_item, &work);
+            scope.spawn(move || loop {
+                let index = next_item.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                if sender.send((index, work(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        // Only the workers' senders are left, so the loop below ends once they have all finished
+        drop(sender);
+
+        let mut finished: Vec<Option<R>> = items.iter().map(|_| None).collect();
+        let mut next_done = 0;
+        for (index, result) in receiver {
+            finished[index] = Some(result);
+            while let Some(result) = finished.get_mut(next_done).and_then(Option::take) {
+                done(&items[next_done], result);
+                next_done += 1;
+            }
+        }
+    });
+}
+
+#[test]
+fn test_run_in_order_reports_in_item_order() {
+    use std::time::Duration;
+
+    // The first items take the longest, so they finish last
+    let items: Vec<u64> = (0..8).collect();
+    let mut reported = vec![];
+    run_in_order(
+        &items,
+        4,
+        |item| {
+            std::thread::sleep(Duration::from_millis((8 - item) * 5));
+            item * 10
+        },
+        |item, result| reported.push((*item, result)),
+    );
+
+    assert_eq!(
+        reported,
+        (0..8).map(|item| (item, item * 10)).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_run_in_order_respects_job_limit() {
+    let running = AtomicUsize::new(0);
+    let most_running = AtomicUsize::new(0);
+    let items: Vec<u32> = (0..12).collect();
+
+    run_in_order(
+        &items,
+        3,
+        |_| {
+            let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+            most_running.fetch_max(now_running, Ordering::SeqCst);
+            std::thread::sleep(std::time::Duration::from_millis(5));
+            running.fetch_sub(1, Ordering::SeqCst);
+        },
+        |_, _| {},
+    );
+
+    assert!(most_running.load(Ordering::SeqCst) <= 3);
+}
+
+#[test]
+fn test_run_day_records_parse_errors_for_every_part() {
+    let day_run = run_day(
+        &crate::days::day_1::Day1,
+        "1000\nabc\n",
+        &Part::BOTH,
+        None,
+        &Budget::new(),
+    );
+
+    assert_eq!(
+        day_run.parse_error.as_deref(),
+        Some("line 2, column 1: expected a number of calories")
+    );
+    assert_eq!(day_run.records.len(), 2);
+    assert!(day_run
+        .records
+        .iter()
+        .all(|record| record.error == day_run.parse_error && record.solve_ns.is_none()));
+}
+
+#[test]
+fn test_run_day_records_cancelled_parts() {
+    let cancel = crate::progress::CancelFlag::new();
+    cancel.cancel();
+    let budget = Budget::new().with_cancel_flag(cancel);
+    let day_run = run_day(
+        &crate::days::day_1::Day1,
+        "1000\n2000\n",
+        &Part::BOTH,
+        None,
+        &budget,
+    );
+
+    assert!(day_run.parse_error.is_none());
+    assert!(day_run
+        .records
+        .iter()
+        .all(|record| record.error.as_deref() == Some("cancelled: interrupted")));
+}
@@ -0,0 +1,226 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// The starting point for a new day's solution. Every `__DAY__` is replaced with the day number.
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs");
+
+const EXAMPLE_ANSWERS: &str = "\
+# Expected answers for example.txt, written like a day in answers.toml:
+# part_one = 24000
+# part_two = 45000
+";
+
+/// Starts a new day in the repository at `root`. Creates `src/days/day_N.rs` from the template
+/// and an empty example in `inputs/tests/day_N/`, then registers the day in `src/days/mod.rs`
+/// as a module, in [`crate::days::all`] and in the fixture tests. Nothing is written unless
+/// every step can be done. Returns the files that were created or changed.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let solution_path = root.join(format!("src/days/day_{}.rs", day));
+    let fixture_dir = root.join(format!("inputs/tests/day_{}", day));
+    let example_path = fixture_dir.join("example.txt");
+    let answers_path = fixture_dir.join("example.toml");
+    let mod_path = root.join("src/days/mod.rs");
+
+    for path in [&solution_path, &example_path, &answers_path] {
+        if path.exists() {
+            return Err(ScaffoldError::AlreadyExists(path.clone()));
+        }
+    }
+    let mod_text = std::fs::read_to_string(&mod_path).map_err(|source| ScaffoldError::Io {
+        path: mod_path.clone(),
+        source,
+    })?;
+    let mod_text = register(&mod_text, day).map_err(|reason| ScaffoldError::CannotRegister {
+        path: mod_path.clone(),
+        reason,
+    })?;
+
+    let write = |path: &Path, contents: &str| {
+        std::fs::write(path, contents).map_err(|source| ScaffoldError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    };
+    std::fs::create_dir_all(&fixture_dir).map_err(|source| ScaffoldError::Io {
+        path: fixture_dir.clone(),
+        source,
+    })?;
+    write(
+        &solution_path,
+        &DAY_TEMPLATE.replace("__DAY__", &day.to_string()),
+    )?;
+    write(&example_path, "")?;
+    write(&answers_path, EXAMPLE_ANSWERS)?;
+    write(&mod_path, &mod_text)?;
+
+    Ok(vec![solution_path, example_path, answers_path, mod_path])
+}
+
+/// Adds `day` to the text of `src/days/mod.rs`: its `pub mod` line (kept in the same sorted
+/// order as the others), its entry in `all()` and its fixture test (both in day order).
+fn register(mod_text: &str, day: u8) -> Result<String, String> {
+    let module_line = format!("pub mod day_{};", day);
+    let mut lines: Vec<String> = mod_text.lines().map(str::to_string).collect();
+    if lines.contains(&module_line) {
+        return Err(format!("day {} is already registered", day));
+    }
+
+    let modules: Vec<usize> = (0..lines.len())
+        .filter(|index| lines[*index].starts_with("pub mod day_"))
+        .collect();
+    let (Some(first), Some(last)) = (modules.first(), modules.last()) else {
+        return Err("no `pub mod day_N;` lines".to_string());
+    };
+    lines.insert(last + 1, module_line);
+    // Sorted on the name alone, the way rustfmt orders them (`day_2` before `day_20`)
+    lines[*first..last + 2].sort_by_key(|line| line.trim_end_matches(';').to_string());
+
+    insert_in_day_order(
+        &mut lines,
+        "Box::new(day_",
+        format!("        Box::new(day_{}::Day{}),", day, day),
+        day,
+    )
+    .ok_or("no `Box::new(day_N::DayN),` lines in `all()`")?;
+    insert_in_day_order(
+        &mut lines,
+        "fixtures_day_",
+        format!("    fixtures_day_{} => {},", day, day),
+        day,
+    )
+    .ok_or("no `fixtures_day_N => N,` lines")?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts `new_line` among the lines whose trimmed text starts with `prefix` followed by a day
+/// number, before the first one for a later day. Only the first run of such lines is used, so
+/// shorter lists further down (like `visualizations()`) are left alone. Returns `None` if there
+/// are no such lines.
+fn insert_in_day_order(
+    lines: &mut Vec<String>,
+    prefix: &str,
+    new_line: String,
+    day: u8,
+) -> Option<()> {
+    let entry = |line: &str| -> Option<u8> {
+        let rest = line.trim().strip_prefix(prefix)?;
+        rest.split(|c: char| !c.is_ascii_digit())
+            .next()?
+            .parse()
+            .ok()
+    };
+    let first = lines.iter().position(|line| entry(line).is_some())?;
+    let entries: Vec<(usize, u8)> = lines[first..]
+        .iter()
+        .map_while(|line| entry(line))
+        .enumerate()
+        .map(|(offset, entry_day)| (first + offset, entry_day))
+        .collect();
+    let last = entries.last()?.0;
+
+    let position = entries
+        .iter()
+        .find(|(_, other_day)| *other_day > day)
+        .map_or(last + 1, |(index, _)| *index);
+    lines.insert(position, new_line);
+    Some(())
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    AlreadyExists(PathBuf),
+    /// `src/days/mod.rs` isn't laid out the way [`new_day`] expects.
+    CannotRegister {
+        path: PathBuf,
+        reason: String,
+    },
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::AlreadyExists(path) => {
+                write!(f, "`{}` already exists", path.display())
+            }
+            ScaffoldError::CannotRegister { path, reason } => {
+                write!(
+                    f,
+                    "could not register the day in `{}`: {}",
+                    path.display(),
+                    reason
+                )
+            }
+            ScaffoldError::Io { path, source } => {
+                write!(f, "could not write `{}`: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScaffoldError::Io { source, .. } => Some(source),
+            ScaffoldError::AlreadyExists(_) | ScaffoldError::CannotRegister { .. } => None,
+        }
+    }
+}
+
+#[test]
+fn test_new_day_registers_in_order() {
+    let root = crate::fixtures::temp_dir("new_day");
+    std::fs::create_dir_all(root.join("src/days")).unwrap();
+    std::fs::copy("./src/days/mod.rs", root.join("src/days/mod.rs")).unwrap();
+
+    new_day(&root, 16).unwrap();
+
+    let mod_text = std::fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+    assert!(mod_text.contains("pub mod day_15;\npub mod day_16;\npub mod day_17;"));
+    assert!(mod_text.contains(
+        "Box::new(day_15::Day15::default()),\n        Box::new(day_16::Day16),\n        Box::new(day_17::Day17::default()),"
+    ));
+    assert!(mod_text.contains("fixtures_day_15 => 15,\n    fixtures_day_16 => 16,\n"));
+
+    let solution = std::fs::read_to_string(root.join("src/days/day_16.rs")).unwrap();
+    assert!(solution.contains("pub struct Day16;"));
+    assert!(solution.contains("const DAY: u8 = 16;"));
+    assert!(solution.contains("./inputs/tests/day_16/example.txt"));
+    assert!(root.join("inputs/tests/day_16/example.toml").exists());
+
+    assert!(matches!(
+        new_day(&root, 16),
+        Err(ScaffoldError::AlreadyExists(_))
+    ));
+}
+
+#[test]
+fn test_new_day_fixtures_pass() {
+    let root = crate::fixtures::temp_dir("new_day_fixtures");
+    std::fs::create_dir_all(root.join("src/days")).unwrap();
+    std::fs::copy("./src/days/mod.rs", root.join("src/days/mod.rs")).unwrap();
+
+    new_day(&root, 16).unwrap();
+
+    // There's nothing to check until the example's answers are filled in
+    crate::fixtures::check_fixtures_in(&root.join("inputs/tests/day_16"), 16);
+}
+
+#[test]
+fn test_new_day_after_the_last() {
+    let mod_text = std::fs::read_to_string("./src/days/mod.rs").unwrap();
+
+    let registered = register(&mod_text, 25).unwrap();
+
+    assert!(registered.contains("pub mod day_21;\npub mod day_25;\npub mod day_3;"));
+    assert!(
+        registered.contains("Box::new(day_21::Day21),\n        Box::new(day_25::Day25),\n    ]")
+    );
+    assert_eq!(registered.matches("Box::new(day_25::Day25)").count(), 1);
+    assert!(registered.contains("fixtures_day_21 => 21,\n    fixtures_day_25 => 25,\n}"));
+    assert!(register(&mod_text, 1).is_err());
+}
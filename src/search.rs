@@ -0,0 +1,260 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Everything a search reached, with the cheapest known way to get there from one of the starts.
+#[derive(Debug, Clone)]
+pub struct SearchResult<N> {
+    /// Cost of the cheapest path to each reached node. Starts have a cost of 0.
+    costs: HashMap<N, u64>,
+    /// The node each reached node was first reached from on its cheapest path. Starts have none.
+    parents: HashMap<N, N>,
+    /// The goal the search stopped at, if it found one.
+    pub goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash> SearchResult<N> {
+    fn new() -> Self {
+        SearchResult {
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The cost of the cheapest path to `node` (the number of steps for [`bfs`]), or `None` if
+    /// it wasn't reached.
+    ///
+    /// When the search stopped early at a goal, nodes that were reached but not yet expanded
+    /// may have a path that is cheaper than this one.
+    pub fn cost(&self, node: &N) -> Option<u64> {
+        self.costs.get(node).copied()
+    }
+
+    /// The cost of reaching [`SearchResult::goal`].
+    pub fn goal_cost(&self) -> Option<u64> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    /// The nodes on the path to `node`, from the start it was reached from to `node` itself.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.costs.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    /// Every node that was reached with the cost of getting there, in no particular order.
+    pub fn reached(&self) -> impl Iterator<Item = (&N, u64)> {
+        self.costs.iter().map(|(node, cost)| (node, *cost))
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.costs.contains_key(node)
+    }
+
+    /// The number of nodes reached, including the starts.
+    pub fn len(&self) -> usize {
+        self.costs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.costs.is_empty()
+    }
+}
+
+/// Breadth first search from every one of `starts` at once, where every step costs 1. Stops as
+/// soon as a node for which `is_goal` is true is reached, or once everything reachable has been,
+/// so passing `|_| false` explores the whole graph.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !result.costs.contains_key(&start) {
+            result.costs.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            result.goal = Some(node);
+            break;
+        }
+        let cost = result.costs[&node] + 1;
+        for neighbour in neighbours(&node) {
+            if !result.costs.contains_key(&neighbour) {
+                result.costs.insert(neighbour.clone(), cost);
+                result.parents.insert(neighbour.clone(), node.clone());
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    result
+}
+
+/// Dijkstra's algorithm from every one of `starts` at once, with `cost` giving the cost of each
+/// step from one node to a neighbour. Stops at the first node for which `is_goal` is true, which
+/// is the cheapest one to reach.
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    cost: impl FnMut(&N, &N) -> u64,
+    is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    astar(starts, neighbours, cost, |_| 0, is_goal)
+}
+
+/// A* search: Dijkstra's algorithm guided towards the goal by `heuristic`, an estimate of the
+/// remaining cost from a node. The path found is only guaranteed to be the cheapest if the
+/// heuristic never overestimates, e.g. the Manhattan distance to the goal on a grid.
+pub fn astar<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut cost: impl FnMut(&N, &N) -> u64,
+    mut heuristic: impl FnMut(&N) -> u64,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new();
+    // The heap only holds indexes into `queued` so that nodes don't have to be `Ord`
+    let mut queued: Vec<N> = vec![];
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if !result.costs.contains_key(&start) {
+            result.costs.insert(start.clone(), 0);
+            heap.push(Reverse((heuristic(&start), 0, queued.len())));
+            queued.push(start);
+        }
+    }
+
+    while let Some(Reverse((_, node_cost, index))) = heap.pop() {
+        let node = queued[index].clone();
+        // A cheaper way to this node was found after this entry was queued
+        if node_cost > result.costs[&node] {
+            continue;
+        }
+        if is_goal(&node) {
+            result.goal = Some(node);
+            break;
+        }
+        for neighbour in neighbours(&node) {
+            let neighbour_cost = node_cost + cost(&node, &neighbour);
+            if result
+                .costs
+                .get(&neighbour)
+                .is_some_and(|known| *known <= neighbour_cost)
+            {
+                continue;
+            }
+            result.costs.insert(neighbour.clone(), neighbour_cost);
+            result.parents.insert(neighbour.clone(), node.clone());
+            heap.push(Reverse((
+                neighbour_cost + heuristic(&neighbour),
+                neighbour_cost,
+                queued.len(),
+            )));
+            queued.push(neighbour);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+fn grid_neighbours(walls: &[&str], (x, y): (usize, usize)) -> Vec<(usize, usize)> {
+    [
+        (x.wrapping_sub(1), y),
+        (x + 1, y),
+        (x, y.wrapping_sub(1)),
+        (x, y + 1),
+    ]
+    .into_iter()
+    .filter(|(x, y)| walls.get(*y).and_then(|row| row.as_bytes().get(*x)) == Some(&b'.'))
+    .collect()
+}
+
+#[test]
+fn test_bfs_path_and_early_exit() {
+    let walls = ["....", ".##.", "...#", "#..."];
+
+    let result = bfs(
+        [(0, 0)],
+        |pos| grid_neighbours(&walls, *pos),
+        |pos| *pos == (3, 3),
+    );
+
+    assert_eq!(result.goal_cost(), Some(6));
+    let path = result.goal_path().unwrap();
+    assert_eq!(path.len(), 7);
+    assert_eq!((path[0], path[6]), ((0, 0), (3, 3)));
+    assert!(path
+        .windows(2)
+        .all(|step| grid_neighbours(&walls, step[0]).contains(&step[1])));
+
+    let everything = bfs([(0, 0)], |pos| grid_neighbours(&walls, *pos), |_| false);
+    assert_eq!(everything.goal, None);
+    assert_eq!(everything.len(), 12);
+    assert_eq!(everything.cost(&(1, 1)), None);
+}
+
+#[test]
+fn test_bfs_from_many_starts() {
+    let line = |n: &i32| [n - 1, n + 1].into_iter().filter(|n| (0..10).contains(n));
+
+    let result = bfs([0, 9], line, |_| false);
+
+    assert_eq!(result.cost(&4), Some(4));
+    assert_eq!(result.cost(&6), Some(3));
+    assert_eq!(result.path_to(&7), Some(vec![9, 8, 7]));
+}
+
+#[test]
+fn test_dijkstra_and_astar_agree() {
+    // The direct route from a to d is shorter in steps but longer in cost
+    let edges: HashMap<char, Vec<(char, u64)>> = HashMap::from([
+        ('a', vec![('b', 1), ('d', 10)]),
+        ('b', vec![('c', 2)]),
+        ('c', vec![('d', 3)]),
+        ('d', vec![]),
+    ]);
+    let neighbours = |node: &char| edges[node].iter().map(|(to, _)| *to).collect::<Vec<_>>();
+    let cost =
+        |from: &char, to: &char| edges[from].iter().find(|(other, _)| other == to).unwrap().1;
+
+    let shortest = dijkstra(['a'], neighbours, cost, |node| *node == 'd');
+    assert_eq!(shortest.goal_cost(), Some(6));
+    assert_eq!(shortest.goal_path(), Some(vec!['a', 'b', 'c', 'd']));
+
+    let guided = astar(
+        ['a'],
+        neighbours,
+        cost,
+        |node| if *node == 'd' { 0 } else { 1 },
+        |node| *node == 'd',
+    );
+    assert_eq!(guided.goal_path(), shortest.goal_path());
+}
@@ -0,0 +1,188 @@
+use crate::error::{Error, ParseError};
+use crate::input::normalise;
+use crate::params::Param;
+use std::any::Any;
+use std::fmt;
+
+/// Which half of a day's puzzle to solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "Part One"),
+            Part::Two => write!(f, "Part Two"),
+        }
+    }
+}
+
+/// The answer to one part of a puzzle. Most days produce a number but some (day 5's crates and
+/// day 10's CRT render) produce text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(num) => write!(f, "{}", num),
+            Answer::Unsigned(num) => write!(f, "{}", num),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(num: i32) -> Self {
+        Answer::Signed(num.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(num: i64) -> Self {
+        Answer::Signed(num)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(num: u32) -> Self {
+        Answer::Unsigned(num.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(num: u64) -> Self {
+        Answer::Unsigned(num)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+/// A single day's puzzle. The input is parsed once and the result is shared by both parts.
+///
+/// When run through [`Runnable`] the input passed to `parse` has already been through
+/// [`normalise`], so it only contains `\n` line endings and has no trailing newline.
+///
+/// Malformed input is reported from `parse` as an [`Error::Parse`] pointing at the offending line
+/// and column. The parts only fail if the input describes a puzzle with no answer.
+pub trait Solution {
+    /// The day of December this solves.
+    const DAY: u8;
+
+    type Parsed;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, Error>;
+    fn part_one(&self, parsed: &Self::Parsed) -> Result<Answer, Error>;
+    fn part_two(&self, parsed: &Self::Parsed) -> Result<Answer, Error>;
+
+    /// Every problem with `input` that would stop it being solved, for `aoc validate`. This
+    /// checks the same things as `parse`, but days whose parsers can carry on past a problem
+    /// override it to report all of them rather than just the first.
+    fn validate(&self, input: &str) -> Vec<ParseError> {
+        match self.parse(input) {
+            Err(Error::Parse(error)) => vec![error],
+            _ => vec![],
+        }
+    }
+
+    /// The numbers the puzzle text gives rather than the input, with the values in use. Days that
+    /// have any keep them as fields that default to the real puzzle's values.
+    fn params(&self) -> Vec<Param> {
+        vec![]
+    }
+
+    /// Changes the parameter called `name`, one of those listed by [`Solution::params`], or says
+    /// why it can't be `value`.
+    fn set_param(&mut self, name: &str, _value: i64) -> Result<(), String> {
+        Err(format!("there is no parameter called `{}`", name))
+    }
+}
+
+/// Object safe view of a [`Solution`] so that every day can be driven from the same list, and
+/// from several threads at once.
+pub trait Runnable: Send + Sync {
+    fn day(&self) -> u8;
+    fn run(&self, input: &str, part: Part) -> Result<Answer, Error>;
+
+    /// Parses `input` without exposing the parsed type. The result can only be passed back to
+    /// [`Runnable::solve`] on the same day, which lets the parse step be timed on its own.
+    fn parse_erased(&self, input: &str) -> Result<Box<dyn Any>, Error>;
+    fn solve(&self, parsed: &dyn Any, part: Part) -> Result<Answer, Error>;
+
+    /// Runs [`Solution::validate`] on the normalised input.
+    fn validate_input(&self, input: &str) -> Vec<ParseError>;
+
+    /// See [`Solution::params`].
+    fn current_params(&self) -> Vec<Param>;
+    /// See [`Solution::set_param`].
+    fn override_param(&mut self, name: &str, value: i64) -> Result<(), String>;
+}
+
+impl<S: Solution + Send + Sync> Runnable for S
+where
+    S::Parsed: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, input: &str, part: Part) -> Result<Answer, Error> {
+        self.solve(self.parse_erased(input)?.as_ref(), part)
+    }
+
+    fn parse_erased(&self, input: &str) -> Result<Box<dyn Any>, Error> {
+        Ok(Box::new(self.parse(&normalise(input))?))
+    }
+
+    fn solve(&self, parsed: &dyn Any, part: Part) -> Result<Answer, Error> {
+        let parsed = parsed
+            .downcast_ref::<S::Parsed>()
+            .expect("parsed input belongs to a different day");
+        match part {
+            Part::One => self.part_one(parsed),
+            Part::Two => self.part_two(parsed),
+        }
+    }
+
+    fn validate_input(&self, input: &str) -> Vec<ParseError> {
+        self.validate(&normalise(input))
+    }
+
+    fn current_params(&self) -> Vec<Param> {
+        self.params()
+    }
+
+    fn override_param(&mut self, name: &str, value: i64) -> Result<(), String> {
+        self.set_param(name, value)
+    }
+}
@@ -0,0 +1,430 @@
+use crate::error::Error;
+use crate::grid::Grid;
+use crate::solution::{Part, Runnable, Solution};
+use std::any::Any;
+use std::fmt;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// One picture of a simulation, drawn with characters the way the puzzle descriptions draw them
+/// (`#` for rock, `o` for sand, ...). Image export colours each character with [`colour`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    cells: Grid<char>,
+    /// Shown above the picture when it is played in the terminal.
+    pub caption: String,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize, fill: char) -> Self {
+        Frame {
+            cells: Grid::new(width, height, fill),
+            caption: String::new(),
+        }
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    /// The cell at `(x, y)`, with `y` counting down from the top row.
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        self.cells.get((x, y)).copied()
+    }
+
+    /// Draws `cell` at `(x, y)`. Anything outside the frame is clipped, so simulations can draw
+    /// a window onto a bigger area without checking every point.
+    pub fn set(&mut self, x: usize, y: usize, cell: char) {
+        if let Some(existing) = self.cells.get_mut((x, y)) {
+            *existing = cell;
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[char]> {
+        self.cells.rows()
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+/// The colour a cell is exported with. Every day uses the same characters for the same things so
+/// that they all share one palette.
+pub fn colour(cell: char) -> [u8; 3] {
+    match cell {
+        '.' | ' ' => [16, 16, 24],
+        '#' => [128, 128, 128],
+        '|' | '-' => [70, 70, 80],
+        'o' => [230, 190, 90],
+        '@' | 'H' => [240, 80, 60],
+        '1'..='9' | 'T' => [240, 160, 60],
+        '~' => [50, 90, 160],
+        '*' => [250, 250, 120],
+        // Day 12's heights, from green valleys up to brown peaks
+        'a'..='z' => {
+            let height = (cell as u32 - 'a' as u32) as f64 / 25.0;
+            let mix = |low: f64, high: f64| (low + (high - low) * height).round() as u8;
+            [mix(40.0, 150.0), mix(120.0, 100.0), mix(50.0, 60.0)]
+        }
+        _ => [255, 255, 255],
+    }
+}
+
+/// A simulation that can be watched. Implemented next to the [`Solution`] it belongs to, and
+/// run through [`Visualization`] so that every day can be driven from the same list.
+pub trait Visualize: Solution {
+    /// Runs the simulation behind `part`, passing every step to `recorder`. The simulation
+    /// should stop as soon as the recorder says no more frames are wanted.
+    fn visualize(
+        &self,
+        parsed: &Self::Parsed,
+        part: Part,
+        recorder: &mut Recorder,
+    ) -> Result<(), Error>;
+}
+
+/// Object safe view of [`Visualize`], in the same way [`Runnable`] is of [`Solution`].
+pub trait Visualization: Runnable {
+    /// `parsed` must come from [`Runnable::parse_erased`] on the same day.
+    fn visualize_erased(
+        &self,
+        parsed: &dyn Any,
+        part: Part,
+        recorder: &mut Recorder,
+    ) -> Result<(), Error>;
+}
+
+impl<V: Visualize + Send + Sync> Visualization for V
+where
+    V::Parsed: 'static,
+{
+    fn visualize_erased(
+        &self,
+        parsed: &dyn Any,
+        part: Part,
+        recorder: &mut Recorder,
+    ) -> Result<(), Error> {
+        let parsed = parsed
+            .downcast_ref::<V::Parsed>()
+            .expect("parsed input belongs to a different day");
+        self.visualize(parsed, part, recorder)
+    }
+}
+
+/// Somewhere frames are sent as they are recorded.
+pub trait FrameSink {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()>;
+
+    /// Called once after the last frame.
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Keeps every frame, mostly for tests.
+impl FrameSink for Vec<Frame> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        self.push(frame.clone());
+        Ok(())
+    }
+}
+
+/// Decides which steps of a simulation become frames and passes them on to a [`FrameSink`].
+/// Frames are only drawn for the steps that are kept, so skipping steps makes long simulations
+/// (day 14's part two drops about 25,000 grains of sand) quick to watch.
+pub struct Recorder<'a> {
+    sink: &'a mut dyn FrameSink,
+    every: usize,
+    max_frames: Option<usize>,
+    steps: usize,
+    recorded: usize,
+    error: Option<io::Error>,
+}
+
+impl<'a> Recorder<'a> {
+    /// Keeps one step in `every`, and at most `max_frames` frames in total.
+    pub fn new(sink: &'a mut dyn FrameSink, every: usize, max_frames: Option<usize>) -> Self {
+        Recorder {
+            sink,
+            every: every.max(1),
+            max_frames,
+            steps: 0,
+            recorded: 0,
+            error: None,
+        }
+    }
+
+    /// Called once for every step of the simulation, with `draw` only called if the step is
+    /// kept. Returns `false` once no more frames are wanted, either because the limit has been
+    /// reached or because the sink failed.
+    pub fn step(&mut self, draw: impl FnOnce() -> Frame) -> bool {
+        let keep = self.steps.is_multiple_of(self.every);
+        self.steps += 1;
+        if keep && self.wants_frames() {
+            self.record(draw());
+        }
+        self.wants_frames()
+    }
+
+    /// Records the final state of the simulation, whether or not its step would have been kept.
+    pub fn last(&mut self, draw: impl FnOnce() -> Frame) {
+        if self.wants_frames() {
+            self.record(draw());
+        }
+    }
+
+    fn wants_frames(&self) -> bool {
+        self.error.is_none() && self.max_frames.is_none_or(|max| self.recorded < max)
+    }
+
+    fn record(&mut self, frame: Frame) {
+        match self.sink.frame(&frame) {
+            Ok(()) => self.recorded += 1,
+            Err(error) => self.error = Some(error),
+        }
+    }
+
+    /// Finishes the sink, returning how many frames were recorded or the first error.
+    pub fn finish(self) -> io::Result<usize> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        self.sink.finish()?;
+        Ok(self.recorded)
+    }
+}
+
+/// Plays frames back in the terminal, redrawing the screen and waiting `delay` after each one.
+pub struct Terminal<W: Write> {
+    out: W,
+    delay: Duration,
+}
+
+impl<W: Write> Terminal<W> {
+    pub fn new(out: W, delay: Duration) -> Self {
+        Terminal { out, delay }
+    }
+}
+
+impl<W: Write> FrameSink for Terminal<W> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        // Clear the screen and move back to the top left
+        write!(self.out, "\x1b[2J\x1b[H{}\n{}\n", frame.caption, frame)?;
+        self.out.flush()?;
+        std::thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+/// Writes every frame to `dir` as a numbered binary PPM image (`frame_00001.ppm`, ...), with each
+/// cell drawn as a `scale` by `scale` square.
+pub struct PpmFrames {
+    dir: PathBuf,
+    scale: usize,
+    written: usize,
+}
+
+impl PpmFrames {
+    pub fn new(dir: impl Into<PathBuf>, scale: usize) -> io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(PpmFrames {
+            dir,
+            scale: scale.max(1),
+            written: 0,
+        })
+    }
+}
+
+impl FrameSink for PpmFrames {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        self.written += 1;
+        let path = self.dir.join(format!("frame_{:05}.ppm", self.written));
+        std::fs::write(path, ppm(frame, self.scale))
+    }
+}
+
+fn ppm(frame: &Frame, scale: usize) -> Vec<u8> {
+    let mut image = format!(
+        "P6\n{} {}\n255\n",
+        frame.width() * scale,
+        frame.height() * scale
+    )
+    .into_bytes();
+    for row in frame.rows() {
+        for _ in 0..scale {
+            for cell in row {
+                let rgb = colour(*cell);
+                for _ in 0..scale {
+                    image.extend_from_slice(&rgb);
+                }
+            }
+        }
+    }
+    image
+}
+
+/// Writes frames as a looping animated GIF. Every frame has to be the same size as the first.
+pub struct Gif<W: Write> {
+    out: Option<W>,
+    encoder: Option<gif::Encoder<W>>,
+    /// In pixels, fixed by the first frame.
+    size: (u16, u16),
+    scale: usize,
+    /// Between frames, in hundredths of a second.
+    delay: u16,
+}
+
+impl<W: Write> Gif<W> {
+    pub fn new(out: W, scale: usize, delay: Duration) -> Self {
+        Gif {
+            out: Some(out),
+            encoder: None,
+            size: (0, 0),
+            scale: scale.max(1),
+            delay: (delay.as_millis() / 10).clamp(1, u16::MAX as u128) as u16,
+        }
+    }
+}
+
+impl<W: Write> FrameSink for Gif<W> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let too_big = || io::Error::new(io::ErrorKind::InvalidInput, "frame too big for a GIF");
+        let width = u16::try_from(frame.width() * self.scale).map_err(|_| too_big())?;
+        let height = u16::try_from(frame.height() * self.scale).map_err(|_| too_big())?;
+
+        let encoder = match (&mut self.encoder, self.out.take()) {
+            (Some(encoder), _) => {
+                if self.size != (width, height) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "every frame of a GIF has to be the same size",
+                    ));
+                }
+                encoder
+            }
+            (None, Some(out)) => {
+                // Cells are ASCII, so each one's palette index is just its character code
+                let palette: Vec<u8> = (0..128u8).flat_map(|code| colour(code as char)).collect();
+                let mut encoder =
+                    gif::Encoder::new(out, width, height, &palette).map_err(io::Error::other)?;
+                encoder
+                    .set_repeat(gif::Repeat::Infinite)
+                    .map_err(io::Error::other)?;
+                self.size = (width, height);
+                self.encoder.insert(encoder)
+            }
+            (None, None) => unreachable!("the output is only taken to make the encoder"),
+        };
+
+        let mut pixels = Vec::with_capacity(width as usize * height as usize);
+        for row in frame.rows() {
+            for _ in 0..self.scale {
+                for cell in row {
+                    let index = if cell.is_ascii() { *cell as u8 } else { b'?' };
+                    pixels.extend(std::iter::repeat_n(index, self.scale));
+                }
+            }
+        }
+        let gif_frame = gif::Frame {
+            width,
+            height,
+            delay: self.delay,
+            buffer: pixels.into(),
+            ..gif::Frame::default()
+        };
+        encoder.write_frame(&gif_frame).map_err(io::Error::other)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        // Dropping the encoder writes the GIF trailer
+        self.encoder.take();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn numbered_frame(number: usize) -> Frame {
+    Frame::new(1, 1, '.').with_caption(number.to_string())
+}
+
+#[test]
+fn test_recorder_keeps_every_nth_step_and_the_last() {
+    let mut frames: Vec<Frame> = vec![];
+    let mut recorder = Recorder::new(&mut frames, 3, None);
+
+    for step in 0..7 {
+        assert!(recorder.step(|| numbered_frame(step)));
+    }
+    recorder.last(|| numbered_frame(99));
+    assert_eq!(recorder.finish().unwrap(), 4);
+
+    let captions: Vec<&str> = frames.iter().map(|frame| frame.caption.as_str()).collect();
+    assert_eq!(captions, ["0", "3", "6", "99"]);
+}
+
+#[test]
+fn test_recorder_stops_at_max_frames() {
+    let mut frames: Vec<Frame> = vec![];
+    let mut recorder = Recorder::new(&mut frames, 1, Some(2));
+
+    assert!(recorder.step(|| numbered_frame(0)));
+    assert!(!recorder.step(|| numbered_frame(1)));
+    recorder.step(|| panic!("no more frames should be drawn"));
+    recorder.last(|| panic!("no more frames should be drawn"));
+
+    assert_eq!(recorder.finish().unwrap(), 2);
+}
+
+#[test]
+fn test_frame_drawing() {
+    let mut frame = Frame::new(3, 2, '.');
+    frame.set(0, 0, '#');
+    frame.set(2, 1, 'o');
+    frame.set(3, 0, '#');
+
+    assert_eq!(frame.to_string(), "#..\n..o");
+    assert_eq!(frame.get(2, 1), Some('o'));
+    assert_eq!(frame.get(3, 0), None);
+
+    let image = ppm(&frame, 2);
+    assert!(image.starts_with(b"P6\n6 4\n255\n"));
+    assert_eq!(image.len(), "P6\n6 4\n255\n".len() + 6 * 4 * 3);
+}
+
+#[test]
+fn test_gif_frames_must_match() {
+    let mut bytes = vec![];
+    {
+        let mut gif = Gif::new(&mut bytes, 2, Duration::from_millis(50));
+
+        gif.frame(&Frame::new(3, 2, '#')).unwrap();
+        gif.frame(&Frame::new(3, 2, 'o')).unwrap();
+        assert_eq!(
+            gif.frame(&Frame::new(2, 2, '#')).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+        gif.finish().unwrap();
+    }
+
+    let mut decoder = gif::DecodeOptions::new().read_info(&bytes[..]).unwrap();
+    assert_eq!((decoder.width(), decoder.height()), (6, 4));
+    let mut num_frames = 0;
+    while decoder.read_next_frame().unwrap().is_some() {
+        num_frames += 1;
+    }
+    assert_eq!(num_frames, 2);
+}
@@ -0,0 +1,51 @@
+use crate::error::{Error, ParseError};
+use crate::input::lines;
+use crate::solution::{Answer, Solution};
+
+pub struct Day__DAY__;
+
+impl Solution for Day__DAY__ {
+    const DAY: u8 = __DAY__;
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>, Error> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn part_one(&self, lines: &Vec<String>) -> Result<Answer, Error> {
+        part_one(lines)
+    }
+
+    fn part_two(&self, lines: &Vec<String>) -> Result<Answer, Error> {
+        part_two(lines)
+    }
+}
+
+/// Reads each line of the input, none of which can be empty.
+pub fn parse_lines(input_text: &str) -> Result<Vec<String>, ParseError> {
+    lines(input_text)
+        .map(|line| {
+            if line.trim().is_empty() {
+                return Err(ParseError::at(input_text, line, "a non-empty line"));
+            }
+            Ok(line.to_string())
+        })
+        .collect()
+}
+
+/// The answer to part one.
+pub fn part_one(_lines: &[String]) -> Result<Answer, Error> {
+    Err(Error::NoAnswer("part one hasn't been solved yet".to_string()))
+}
+
+/// The answer to part two.
+pub fn part_two(_lines: &[String]) -> Result<Answer, Error> {
+    Err(Error::NoAnswer("part two hasn't been solved yet".to_string()))
+}
+
+#[test]
+fn test_parse_example() {
+    let input_text = std::fs::read_to_string("./inputs/tests/day___DAY__/example.txt").unwrap();
+
+    assert!(parse_lines(&input_text).is_ok());
+}